@@ -0,0 +1,90 @@
+//! Checks the circuit hashes pinned in `src/circuit_hashes.rs` against `build/<name>.arcis`.
+//!
+//! Run `arcium build` first: the build fails when a `.arcis` is missing, when a pin is still
+//! zero, or when a sha256 differs from its pin (with the line to pin), since a comp-def
+//! registered with any other hash would reject the file the MXE fetches. A `.arcis` older
+//! than `encrypted-ixs` is only a `cargo:warning`.
+
+use sha2::{Digest, Sha256};
+use std::{env, fs, path::Path, path::PathBuf};
+
+/// (circuit name, pinned const)
+const CIRCUITS: &[(&str, &str)] = &[
+    ("plan_payout", "CIRCUIT_HASH_PLAN_PAYOUT"),
+    ("reseal_destination", "CIRCUIT_HASH_RESEAL"),
+];
+
+fn warn(msg: impl AsRef<str>) {
+    println!("cargo:warning={}", msg.as_ref());
+}
+
+/// Bytes of `pub const <konst>: [u8; 32] = [..];` in the pinned file.
+fn pinned(src: &str, konst: &str) -> Option<Vec<u8>> {
+    let decl = format!("pub const {konst}: [u8; 32] = [");
+    let start = src.find(&decl)? + decl.len();
+    let body = &src[start..start + src[start..].find(']')?];
+    if let Some((byte, len)) = body.split_once(';') {
+        return Some(vec![byte.trim().parse().ok()?; len.trim().parse().ok()?]);
+    }
+    body.split(',')
+        .map(str::trim)
+        .filter(|b| !b.is_empty())
+        .map(|b| b.parse().ok())
+        .collect()
+}
+
+fn newer_or_same(a: &Path, b: &Path) -> bool {
+    let mtime = |p: &Path| fs::metadata(p).and_then(|m| m.modified()).ok();
+    match (mtime(a), mtime(b)) {
+        (Some(a), Some(b)) => a >= b,
+        _ => true,
+    }
+}
+
+fn main() {
+    let manifest = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let pinned_file = manifest.join("src/circuit_hashes.rs");
+    let root = manifest.join("../..");
+    let circuits_src = root.join("encrypted-ixs/src/lib.rs");
+    let build_dir = root.join("build");
+
+    println!("cargo:rerun-if-changed={}", pinned_file.display());
+    println!("cargo:rerun-if-changed={}", circuits_src.display());
+    let pins = fs::read_to_string(&pinned_file)
+        .unwrap_or_else(|e| panic!("cannot read {}: {e}", pinned_file.display()));
+
+    for (name, konst) in CIRCUITS {
+        let arcis = build_dir.join(format!("{name}.arcis"));
+        println!("cargo:rerun-if-changed={}", arcis.display());
+
+        let pin = pinned(&pins, konst)
+            .unwrap_or_else(|| panic!("{konst} is not a [u8; 32] in src/circuit_hashes.rs"));
+        let bytes = fs::read(&arcis).unwrap_or_else(|e| {
+            panic!(
+                "cannot read {} ({e}); run `arcium build` to check {konst}",
+                arcis.display()
+            )
+        });
+        if !newer_or_same(&arcis, &circuits_src) {
+            warn(format!(
+                "{} is older than encrypted-ixs/src/lib.rs; run `arcium build` again",
+                arcis.display()
+            ));
+        }
+
+        let hash = Sha256::digest(&bytes);
+        if hash.as_slice() != pin.as_slice() {
+            let what = if pin.iter().all(|&b| b == 0) {
+                "is not pinned"
+            } else {
+                "does not match"
+            };
+            panic!(
+                "{konst} {what} {}; pin it in src/circuit_hashes.rs: \
+                 pub const {konst}: [u8; 32] = {:?};",
+                arcis.display(),
+                hash.as_slice()
+            );
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Attestations: digests (see `crate::digest`) signed off-chain by keys in the `AttesterSet`.
+//!
+//! The signatures are verified by the native precompiles, which the client places in the
+//! same transaction; here we only read those instructions back from the instructions
+//! sysvar and check *what* they verified:
+//!
+//! - secp256k1: the message must be the EIP-191 personal message of the digest
+//!   (`"\x19Ethereum Signed Message:\n32" || digest`, i.e. `signMessage(digest)` in ethers)
+//!   and the address a registered EVM attester.
+//! - ed25519: the message must be the raw 32-byte digest and the key a registered one.
+//!
+//! Only offsets pointing into the precompile instruction itself are accepted, so the data
+//! read here is the data that was verified. Each registered key counts once.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked;
+use anchor_lang::solana_program::{ed25519_program, secp256k1_program};
+
+use crate::state::{AttesterSet, MAX_ATTESTERS};
+
+pub const EIP191_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n32";
+
+const SECP256K1_OFFSETS_START: usize = 1;
+const SECP256K1_OFFSETS_LEN: usize = 11;
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+/// ed25519 instruction index meaning "this instruction"
+const ED25519_CURRENT_IX: u16 = u16::MAX;
+
+/// Number of distinct registered attesters that signed `digest` in this transaction.
+/// Without the attester set or the instructions sysvar nothing is counted.
+pub fn count_attestations(
+    set: Option<&AttesterSet>,
+    ix_sysvar: Option<&AccountInfo>,
+    digest: &[u8; 32],
+) -> Result<u8> {
+    let (Some(set), Some(ix_sysvar)) = (set, ix_sysvar) else {
+        return Ok(0);
+    };
+
+    let ix_count = {
+        let data = ix_sysvar.try_borrow_data()?;
+        match data.get(..2) {
+            Some(n) => u16::from_le_bytes([n[0], n[1]]),
+            None => return Ok(0),
+        }
+    };
+
+    let mut eip191 = [0u8; 60];
+    eip191[..28].copy_from_slice(EIP191_PREFIX);
+    eip191[28..].copy_from_slice(digest);
+
+    // bit i: evm[i], bit MAX_ATTESTERS + i: ed25519[i]
+    let mut seen: u16 = 0;
+    for index in 0..ix_count {
+        let ix = load_instruction_at_checked(index as usize, ix_sysvar)?;
+        if ix.program_id == secp256k1_program::ID {
+            for (address, message) in secp256k1_entries(&ix, index) {
+                if message == eip191.as_slice() {
+                    if let Some(i) = set.evm_index(address) {
+                        seen |= 1 << i;
+                    }
+                }
+            }
+        } else if ix.program_id == ed25519_program::ID {
+            for (key, message) in ed25519_entries(&ix, index) {
+                if message == digest.as_slice() {
+                    if let Some(i) = set.ed25519_index(key) {
+                        seen |= 1 << (MAX_ATTESTERS + i);
+                    }
+                }
+            }
+        }
+    }
+    Ok(seen.count_ones() as u8)
+}
+
+fn read_u16(data: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(data.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn slice(data: &[u8], offset: u16, len: usize) -> Option<&[u8]> {
+    data.get(offset as usize..offset as usize + len)
+}
+
+/// (eth address, message) of every signature whose data lives in this instruction.
+fn secp256k1_entries(ix: &Instruction, index: u16) -> Vec<(&[u8], &[u8])> {
+    let data = &ix.data;
+    let count = data.first().copied().unwrap_or(0) as usize;
+    (0..count)
+        .filter_map(|k| {
+            let o = SECP256K1_OFFSETS_START + k * SECP256K1_OFFSETS_LEN;
+            let offsets = data.get(o..o + SECP256K1_OFFSETS_LEN)?;
+            // [sig_offset u16, sig_ix u8, addr_offset u16, addr_ix u8,
+            //  msg_offset u16, msg_len u16, msg_ix u8]
+            let (addr_ix, msg_ix) = (offsets[5] as u16, offsets[10] as u16);
+            if addr_ix != index || msg_ix != index {
+                return None;
+            }
+            let address = slice(data, read_u16(offsets, 3)?, 20)?;
+            let message = slice(data, read_u16(offsets, 6)?, read_u16(offsets, 8)? as usize)?;
+            Some((address, message))
+        })
+        .collect()
+}
+
+/// (public key, message) of every signature whose data lives in this instruction.
+fn ed25519_entries(ix: &Instruction, index: u16) -> Vec<(&[u8], &[u8])> {
+    let data = &ix.data;
+    let count = data.first().copied().unwrap_or(0) as usize;
+    let here = |i: u16| i == ED25519_CURRENT_IX || i == index;
+    (0..count)
+        .filter_map(|k| {
+            let o = ED25519_OFFSETS_START + k * ED25519_OFFSETS_LEN;
+            let offsets = data.get(o..o + ED25519_OFFSETS_LEN)?;
+            // [sig_offset, sig_ix, pubkey_offset, pubkey_ix, msg_offset, msg_len, msg_ix], u16 each
+            if !here(read_u16(offsets, 6)?) || !here(read_u16(offsets, 12)?) {
+                return None;
+            }
+            let key = slice(data, read_u16(offsets, 4)?, 32)?;
+            let message = slice(data, read_u16(offsets, 8)?, read_u16(offsets, 10)? as usize)?;
+            Some((key, message))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secp_ix(addr_ix: u8, msg_ix: u8, address: [u8; 20], message: &[u8]) -> Instruction {
+        // header (1) + offsets (11) + sig (65) + address (20) + message
+        let (sig_at, addr_at, msg_at) = (12u16, 77u16, 97u16);
+        let mut data = vec![1u8];
+        data.extend_from_slice(&sig_at.to_le_bytes());
+        data.push(addr_ix);
+        data.extend_from_slice(&addr_at.to_le_bytes());
+        data.push(addr_ix);
+        data.extend_from_slice(&msg_at.to_le_bytes());
+        data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+        data.push(msg_ix);
+        data.extend_from_slice(&[0u8; 65]);
+        data.extend_from_slice(&address);
+        data.extend_from_slice(message);
+        Instruction::new_with_bytes(secp256k1_program::ID, &data, vec![])
+    }
+
+    fn ed_ix(key_ix: u16, msg_ix: u16, key: [u8; 32], message: &[u8]) -> Instruction {
+        // header (2) + offsets (14) + sig (64) + key (32) + message
+        let (sig_at, key_at, msg_at) = (16u16, 80u16, 112u16);
+        let mut data = vec![1u8, 0];
+        for v in [
+            sig_at,
+            ED25519_CURRENT_IX,
+            key_at,
+            key_ix,
+            msg_at,
+            message.len() as u16,
+            msg_ix,
+        ] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+        data.extend_from_slice(&[0u8; 64]);
+        data.extend_from_slice(&key);
+        data.extend_from_slice(message);
+        Instruction::new_with_bytes(ed25519_program::ID, &data, vec![])
+    }
+
+    #[test]
+    fn secp256k1_reads_own_data_only() {
+        let ix = secp_ix(2, 2, [7; 20], b"msg");
+        assert_eq!(
+            secp256k1_entries(&ix, 2),
+            vec![([7u8; 20].as_slice(), b"msg".as_slice())]
+        );
+        // offsets into another instruction are not what we would read
+        assert!(secp256k1_entries(&ix, 1).is_empty());
+        assert!(secp256k1_entries(&secp_ix(2, 0, [7; 20], b"msg"), 2).is_empty());
+    }
+
+    #[test]
+    fn ed25519_reads_own_data_only() {
+        let ix = ed_ix(ED25519_CURRENT_IX, ED25519_CURRENT_IX, [9; 32], b"msg");
+        assert_eq!(
+            ed25519_entries(&ix, 0),
+            vec![([9u8; 32].as_slice(), b"msg".as_slice())]
+        );
+        assert_eq!(ed25519_entries(&ed_ix(3, 3, [9; 32], b"msg"), 3).len(), 1);
+        assert!(ed25519_entries(&ed_ix(1, ED25519_CURRENT_IX, [9; 32], b"msg"), 3).is_empty());
+    }
+
+    #[test]
+    fn truncated_data_is_ignored() {
+        let mut ix = secp_ix(0, 0, [7; 20], b"msg");
+        ix.data.truncate(90);
+        assert!(secp256k1_entries(&ix, 0).is_empty());
+        let ix = Instruction::new_with_bytes(ed25519_program::ID, &[3, 0, 1], vec![]);
+        assert!(ed25519_entries(&ix, 0).is_empty());
+    }
+}
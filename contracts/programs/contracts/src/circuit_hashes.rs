@@ -0,0 +1,6 @@
+// sha256 of build/<circuit>.arcis, the file the comp-def's off-chain source must serve.
+// Re-pin after every `arcium build` that changes a circuit: build.rs fails with the new
+// value when they drift, and while a pin is still zero.
+
+pub const CIRCUIT_HASH_PLAN_PAYOUT: [u8; 32] = [0; 32];
+pub const CIRCUIT_HASH_RESEAL: [u8; 32] = [0; 32];
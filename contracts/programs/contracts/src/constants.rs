@@ -4,7 +4,5 @@ pub const COMP_DEF_OFFSET_PLAN_PAYOUT: u32 = comp_def_offset("plan_payout");
 
 pub const COMP_DEF_OFFSET_RESEAL: u32 = comp_def_offset("reseal_destination");
 
-pub const CIRCUIT_URL_PLAN_PAYOUT: &str =
-    "https://gateway.pinata.cloud/ipfs/bafybeibc4lsyclfigtwsdy4julinpfgqynbyfvpkaufjxs3ff5ytcqiswi";
-pub const CIRCUIT_URL_RESEAL: &str =
-    "https://gateway.pinata.cloud/ipfs/bafybeibc4lsyclfigtwsdy4julinpfgqynbyfvpkaufjxs3ff5ytcqiswi";
+// CIRCUIT_HASH_PLAN_PAYOUT, CIRCUIT_HASH_RESEAL (checked by build.rs)
+include!("circuit_hashes.rs");
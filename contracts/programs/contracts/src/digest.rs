@@ -0,0 +1,115 @@
+//! Digests of the actions relayers approve (settlements and inbound deliveries).
+//!
+//! A digest is `sha256(domain || program_id || fields...)`. Integers are little-endian,
+//! hashes and keys are raw 32-byte values, EVM addresses are the raw 20 bytes:
+//!
+//! | action     | fields after the domain and the program id                                                   |
+//! |------------|----------------------------------------------------------------------------------------------|
+//! | settlement | `request_id: u64`, `payer: [32]`, `created_at: i64`, `solver: [32]`, `claim_deadline: i64`, `dest_chain_id: u64`, `dest_tx_hash: [32]`, `evidence_hash: [32]`, `dest_token: [20]`, `dest_amount: u128` |
+//! | delivery   | `source_chain_id: u64`, `source_request_id: [32]` (uint256 BE), `source_tx_hash: [32]`, `recipient: [32]`, `token: [32]`, `amount: u64` |
+//!
+//! The domain names the action and the encoding version, so a vote for one action can
+//! never be replayed as a vote for another. `created_at` tells apart requests that reuse a
+//! `request_id` after the previous one was closed, so old votes never carry over; `solver`
+//! and `claim_deadline` pin the claim, so votes for a fill never pay a later claimant.
+//! Relayers approve a digest on-chain (`vote_settlement` / `vote_delivery`); attesters
+//! sign it off-chain (`crate::attestation`).
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::state::BridgeRequest;
+
+pub const SETTLEMENT_DOMAIN: &[u8] = b"arcium-bridge:settle:v2";
+pub const DELIVERY_DOMAIN: &[u8] = b"arcium-bridge:deliver:v1";
+
+pub fn settlement_digest(
+    req: &BridgeRequest,
+    dest_tx_hash: &[u8; 32],
+    evidence_hash: &[u8; 32],
+    dest_token: &[u8; 20],
+    dest_amount: u128,
+) -> [u8; 32] {
+    hashv(&[
+        SETTLEMENT_DOMAIN,
+        crate::ID.as_ref(),
+        &req.request_id.to_le_bytes(),
+        req.payer.as_ref(),
+        &req.created_at.to_le_bytes(),
+        req.solver.as_ref(),
+        &req.claim_deadline.to_le_bytes(),
+        &req.dest_chain_id.to_le_bytes(),
+        dest_tx_hash,
+        evidence_hash,
+        dest_token,
+        &dest_amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+pub fn delivery_digest(
+    source_chain_id: u64,
+    source_request_id: &[u8; 32],
+    source_tx_hash: &[u8; 32],
+    recipient: &Pubkey,
+    token: &Pubkey,
+    amount: u64,
+) -> [u8; 32] {
+    hashv(&[
+        DELIVERY_DOMAIN,
+        crate::ID.as_ref(),
+        &source_chain_id.to_le_bytes(),
+        source_request_id,
+        source_tx_hash,
+        recipient.as_ref(),
+        token.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::BridgeRequestV0;
+
+    fn claimed_request(solver: Pubkey, claim_deadline: i64) -> BridgeRequest {
+        BridgeRequestV0 {
+            request_id: 1,
+            payer: Pubkey::new_from_array([1u8; 32]),
+            token_mint: Pubkey::new_from_array([2u8; 32]),
+            amount_locked: 1_000,
+            fee_locked: 10,
+            created_at: 1_700_000_000,
+            claimed: true,
+            solver,
+            claim_deadline,
+            bond_lamports: 0,
+            finalized: false,
+            bump: 255,
+            client_pubkey: [0u8; 32],
+            nonce_le: 0,
+            dest_ct_w0: [0u8; 32],
+            dest_ct_w1: [0u8; 32],
+            dest_ct_w2: [0u8; 32],
+            dest_ct_w3: [0u8; 32],
+        }
+        .upgrade(1_200, 0, 0, 0)
+    }
+
+    fn digest(req: &BridgeRequest) -> [u8; 32] {
+        settlement_digest(req, &[9u8; 32], &[8u8; 32], &[7u8; 20], 1_000)
+    }
+
+    #[test]
+    fn settlement_digest_is_bound_to_the_claim() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let first = digest(&claimed_request(a, 1_700_001_200));
+
+        assert_eq!(first, digest(&claimed_request(a, 1_700_001_200)));
+        // another solver claiming after the first claim expired
+        assert_ne!(first, digest(&claimed_request(b, 1_700_001_200)));
+        // the same solver claiming again
+        assert_ne!(first, digest(&claimed_request(a, 1_700_002_400)));
+    }
+}
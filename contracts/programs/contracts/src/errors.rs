@@ -8,7 +8,7 @@ pub enum ErrorCode {
     InvalidMint,
     #[msg("Invalid token owner")]
     InvalidOwner,
-    #[msg("Only owner can modify config")]
+    #[msg("Only the admin can modify config")]
     OnlyOwner,
     #[msg("Request already finalized")]
     RequestAlreadyFinalized,
@@ -34,4 +34,138 @@ pub enum ErrorCode {
     InsufficientEscrowBalance,
     #[msg("The computation was aborted")]
     AbortedComputation,
+    #[msg("Refund timeout has not elapsed yet")]
+    RefundTimeoutNotReached,
+    #[msg("Invalid request status transition")]
+    InvalidStatusTransition,
+    #[msg("Bond is still in a legacy vault; call migrate_bond_vault first")]
+    LegacyBondVault,
+    #[msg("Bond vault already migrated")]
+    BondVaultAlreadyMigrated,
+    #[msg("Request is not settled or refunded")]
+    RequestNotFinalized,
+    #[msg("Close grace period has not elapsed yet")]
+    CloseGracePeriodActive,
+    #[msg("Token is not enabled for bridging")]
+    TokenDisabled,
+    #[msg("Deposit is below the token minimum")]
+    DepositBelowMinimum,
+    #[msg("Deposit is above the token maximum")]
+    DepositAboveMaximum,
+    #[msg("No enabled route for this token and destination chain")]
+    TokenRouteDisabled,
+    #[msg("Destination token does not match the request")]
+    DestTokenMismatch,
+    #[msg("Destination amount is below the expected amount")]
+    DestAmountTooLow,
+    #[msg("Destination chain is not enabled")]
+    ChainDisabled,
+    #[msg("No fees to collect")]
+    NoFeesToCollect,
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+    #[msg("Signer is not an authorized relayer")]
+    OnlyRelayer,
+    #[msg("Relayer already registered")]
+    RelayerAlreadyExists,
+    #[msg("Relayer not registered")]
+    RelayerNotFound,
+    #[msg("Relayer set is full")]
+    TooManyRelayers,
+    #[msg("Signer is not allowed to collect fees")]
+    OnlyFeeCollector,
+    #[msg("Solver is not authorized")]
+    SolverNotAuthorized,
+    #[msg("x25519 key does not match the solver registry")]
+    SolverX25519Mismatch,
+    #[msg("Only the pauser or the admin can change the pause flags")]
+    OnlyPauser,
+    #[msg("Deposits are paused")]
+    DepositsPaused,
+    #[msg("Claims are paused")]
+    ClaimsPaused,
+    #[msg("Settlements are paused")]
+    SettlementsPaused,
+    #[msg("Deliveries are paused")]
+    DeliveriesPaused,
+    #[msg("Unknown pause flag")]
+    InvalidPauseFlags,
+    #[msg("Config change timelock has not elapsed yet")]
+    TimelockNotElapsed,
+    #[msg("fee_bps is above the maximum")]
+    FeeBpsTooHigh,
+    #[msg("min_fee is above max_fee")]
+    MinFeeAboveMaxFee,
+    #[msg("Basis points above 10000")]
+    BpsOutOfRange,
+    #[msg("Duration must not be negative")]
+    NegativeDuration,
+    #[msg("min_deposit is above max_deposit")]
+    MinDepositAboveMaxDeposit,
+    #[msg("Account is already in the current layout")]
+    AccountAlreadyMigrated,
+    #[msg("Account layout not recognised")]
+    UnknownAccountLayout,
+    #[msg("Circuit versions must be registered sequentially")]
+    InvalidCircuitVersion,
+    #[msg("Circuit version is already active")]
+    CircuitVersionAlreadyActive,
+    #[msg("Request has not been attested by the MPC")]
+    RequestNotAttested,
+    #[msg("The reseal of this claim has not failed")]
+    ResealNotFailed,
+    #[msg("The attestation of this request has not failed")]
+    AttestationNotFailed,
+    #[msg("Delivery to a new account is below the rent-exempt minimum")]
+    DeliveryBelowRentExemption,
+    #[msg("Relayer threshold must be between 1 and the number of relayers")]
+    InvalidRelayerThreshold,
+    #[msg("Relayer already voted for this action")]
+    AlreadyVoted,
+    #[msg("Votes were cast for a different action")]
+    VoteDigestMismatch,
+    #[msg("Voting round has expired")]
+    VotesExpired,
+    #[msg("Not enough relayer approvals")]
+    QuorumNotReached,
+    #[msg("Attester already registered")]
+    AttesterAlreadyExists,
+    #[msg("Attester not registered")]
+    AttesterNotFound,
+    #[msg("Attester set is full")]
+    TooManyAttesters,
+    #[msg("Attester key is not valid for its scheme")]
+    InvalidAttesterKey,
+    #[msg("Legacy bond vault holds less than the request's bond")]
+    LegacyBondVaultUnderfunded,
+    #[msg("Request funds are still in the shared escrow; migrate them first")]
+    LegacyEscrow,
+    #[msg("Request funds are already in its own escrow")]
+    EscrowAlreadyMigrated,
+    #[msg("Destination decimals too far from the mint decimals")]
+    DestDecimalsOutOfRange,
+    #[msg("Circuit hash is not pinned in circuit_hashes.rs")]
+    CircuitHashNotPinned,
+    #[msg("plan_payout is already queued for this request")]
+    AttestationInFlight,
+    #[msg("Client x25519 public key must not be zero")]
+    InvalidClientPubkey,
+    #[msg("Duration is above its maximum")]
+    DurationTooLong,
+    #[msg("An open V0 request needs its token route and token config to migrate")]
+    LegacyRouteRequired,
+    #[msg("Only the payer can migrate an open request")]
+    OnlyPayer,
+    #[msg("Comp-def is not this version of the circuit")]
+    CompDefCircuitMismatch,
+    #[msg("Hash does not match the comp-def's circuit source")]
+    CircuitHashMismatch,
+    #[msg("Bond vault and solver accounts are required for a bonded claim")]
+    ClaimAccountsRequired,
+    #[msg("Escrow still holds tokens; pass the payer's token account to sweep them")]
+    EscrowNotEmpty,
+    #[msg("Chain config account is required for a per-chain change")]
+    ChainConfigRequired,
+    #[msg("A claimed V0 request must be released before it migrates")]
+    LegacyClaimNotReleased,
 }
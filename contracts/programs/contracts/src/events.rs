@@ -1,3 +1,4 @@
+use crate::state::{RequestStatus, SignatureScheme};
 use anchor_lang::prelude::*;
 
 #[event]
@@ -8,11 +9,17 @@ pub struct BridgeInitiated {
     pub amount_after_fee: u64, // net amount
     pub fee: u64,              // locked fee
     pub ts: u64,               // timestamp
+    pub dest_chain_id: u64,
+    pub dest_token: [u8; 20],
+    pub expected_dest_amount: u128, // net amount in destination token units
+    pub dest_gas_fee: u64,          // lamports, on top of `fee`
 }
 
 #[event]
 pub struct AttestationQueued {
     pub nonce: [u8; 16],
+    pub request_id: u64,
+    pub payer: Pubkey,
 }
 
 #[event]
@@ -20,7 +27,15 @@ pub struct BridgePaidToSolver {
     pub request_id: u64,
     pub solver: Pubkey,
     pub token_mint: Pubkey,
-    pub payout: u64, // amount_locked + fee_locked
+    pub payout: u64,       // amount_locked + fee_locked - protocol_fee
+    pub protocol_fee: u64, // accrued in the mint's fee vault
+}
+
+#[event]
+pub struct FeesCollected {
+    pub token: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,
 }
 
 #[event]
@@ -44,6 +59,7 @@ pub struct BridgeVerifiedUrl {
     pub dest_tx_hash: [u8; 32],
     pub evidence_hash: [u8; 32],
     pub evidence_url: String,
+    pub dest_amount: u128, // amount delivered on the destination chain
 }
 
 #[event]
@@ -51,6 +67,9 @@ pub struct IncomingBridgeDelivered {
     pub recipient: Pubkey,
     pub token: Pubkey, // SPL mint; for SOL use WSOL mint
     pub amount: u64,
+    pub source_chain_id: u64,
+    pub source_request_id: [u8; 32],
+    pub source_tx_hash: [u8; 32],
 }
 
 #[event]
@@ -60,3 +79,264 @@ pub struct BridgeClaimed {
     pub bond: u64,     // lamports locked as bond
     pub deadline: i64, // unix ts until the claim expires
 }
+
+#[event]
+pub struct BondVaultMigrated {
+    pub request_id: u64,
+    pub solver: Pubkey,
+    pub bond: u64, // lamports moved out of the legacy vault
+}
+
+#[event]
+pub struct BridgeRequestClosed {
+    pub request_id: u64,
+    pub payer: Pubkey,
+    pub bond_dust: u64, // lamports swept from the bond vault
+}
+
+#[event]
+pub struct RequestFrozen {
+    pub request_id: u64,
+    pub payer: Pubkey,
+    pub from: RequestStatus,
+    pub by: Pubkey,
+}
+
+#[event]
+pub struct RequestCancelled {
+    pub request_id: u64,
+    pub payer: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub bond_returned: u64, // lamports returned unslashed to the frozen claim's solver
+}
+
+#[event]
+pub struct ChainConfigUpdated {
+    pub chain_id: u64,
+    pub enabled: bool,
+    pub claim_window_secs: i64,
+    pub min_solver_bond: u64,
+    pub dest_gas_fee_lamports: u64,
+}
+
+#[event]
+pub struct TokenRouteUpdated {
+    pub mint: Pubkey,
+    pub dest_chain_id: u64,
+    pub dest_token: [u8; 20],
+    pub dest_decimals: u8,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct TokenConfigUpdated {
+    pub mint: Pubkey,
+    pub version: u32,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct BridgeRefunded {
+    pub request_id: u64,
+    pub payer: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64, // amount_locked + fee_locked
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey, // default = proposal cancelled
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct RelayerAdded {
+    pub relayer: Pubkey,
+}
+
+#[event]
+pub struct RelayerRemoved {
+    pub relayer: Pubkey,
+}
+
+#[event]
+pub struct FeeCollectorUpdated {
+    pub previous: Pubkey,
+    pub fee_collector: Pubkey,
+}
+
+#[event]
+pub struct SlashCollectorUpdated {
+    pub previous: Pubkey,
+    pub slash_collector: Pubkey,
+}
+
+#[event]
+pub struct PauserUpdated {
+    pub previous: Pubkey,
+    pub pauser: Pubkey,
+}
+
+#[event]
+pub struct SolverAuthorized {
+    pub solver: Pubkey,
+    pub x25519: [u8; 32],
+}
+
+#[event]
+pub struct SolverRevoked {
+    pub solver: Pubkey,
+}
+
+#[event]
+pub struct Paused {
+    pub flags: u8,  // bits newly paused
+    pub paused: u8, // full mask after the change
+    pub by: Pubkey,
+}
+
+#[event]
+pub struct Unpaused {
+    pub flags: u8,  // bits newly unpaused
+    pub paused: u8, // full mask after the change
+    pub by: Pubkey,
+}
+
+#[event]
+pub struct ConfigChangeProposed {
+    pub claim_window_secs: Option<i64>,
+    pub min_solver_bond: Option<u64>,
+    pub slash_bps: Option<u16>,
+    pub protocol_fee_bps: Option<u16>,
+    pub config_timelock_secs: Option<i64>,
+    pub chain_id: u64,
+    pub chain_claim_window_secs: Option<i64>,
+    pub chain_min_solver_bond: Option<u64>,
+    pub eta: i64,
+}
+
+#[event]
+pub struct ConfigChangeExecuted {
+    pub claim_window_secs: Option<i64>,
+    pub min_solver_bond: Option<u64>,
+    pub slash_bps: Option<u16>,
+    pub protocol_fee_bps: Option<u16>,
+    pub config_timelock_secs: Option<i64>,
+    pub chain_id: u64,
+    pub chain_claim_window_secs: Option<i64>,
+    pub chain_min_solver_bond: Option<u64>,
+}
+
+#[event]
+pub struct ConfigChangeCancelled {
+    pub eta: i64,
+}
+
+#[event]
+pub struct ConfigMigrated {
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[event]
+pub struct RequestMigrated {
+    pub request_id: u64,
+    pub payer: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[event]
+pub struct CircuitVersionRegistered {
+    pub circuit: u8,
+    pub version: u32,
+    pub comp_def_offset: u32,
+    pub hash: [u8; 32],
+}
+
+#[event]
+pub struct CircuitVersionActivated {
+    pub circuit: u8,
+    pub version: u32,
+    pub comp_def_offset: u32,
+}
+
+#[event]
+pub struct AttestationFailed {
+    pub request_id: u64,
+    pub payer: Pubkey,
+}
+
+#[event]
+pub struct DestinationResealed {
+    pub request_id: u64,
+    pub solver: Pubkey,
+    pub nonce: [u8; 16],
+    pub ct_w0: [u8; 32],
+    pub ct_w1: [u8; 32],
+    pub ct_w2: [u8; 32],
+    pub ct_w3: [u8; 32],
+}
+
+#[event]
+pub struct DestinationResealFailed {
+    pub request_id: u64,
+    pub solver: Pubkey,
+}
+
+#[event]
+pub struct BridgeClaimReleased {
+    pub request_id: u64,
+    pub solver: Pubkey,
+    pub bond_returned: u64,
+}
+
+#[event]
+pub struct ComputationRequeued {
+    pub request_id: u64,
+    pub circuit: u8, // CIRCUIT_* id
+    pub computation_offset: u64,
+    pub failures: u8,
+}
+
+#[event]
+pub struct RelayerThresholdSet {
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ActionVoted {
+    pub digest: [u8; 32],
+    pub kind: u8, // VOTE_KIND_*
+    pub relayer: Pubkey,
+    pub votes: u8,
+    pub threshold: u8,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct AttesterAdded {
+    pub scheme: SignatureScheme,
+    pub key: [u8; 32], // EVM address right-aligned for Secp256k1
+}
+
+#[event]
+pub struct AttesterRemoved {
+    pub scheme: SignatureScheme,
+    pub key: [u8; 32],
+}
+
+#[event]
+pub struct RequestEscrowMigrated {
+    pub request_id: u64,
+    pub payer: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,32 @@
+use crate::errors::ErrorCode;
+use crate::events::AdminTransferred;
+use crate::state::BridgeConfig;
+use anchor_lang::prelude::*;
+
+/// Step 2 of the admin handover: the proposed key signs to take over.
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.pending_admin != Pubkey::default()
+            && config.pending_admin == new_admin.key() @ ErrorCode::NotPendingAdmin
+    )]
+    pub config: Account<'info, BridgeConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let cfg = &mut ctx.accounts.config;
+    let previous = cfg.admin;
+    cfg.admin = ctx.accounts.new_admin.key();
+    cfg.pending_admin = Pubkey::default();
+
+    emit!(AdminTransferred {
+        previous,
+        admin: cfg.admin,
+    });
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use crate::errors::ErrorCode;
+use crate::events::AdminProposed;
+use crate::state::BridgeConfig;
+use anchor_lang::prelude::*;
+
+/// Step 1 of the admin handover. Proposing `Pubkey::default()` cancels a pending proposal.
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+}
+
+pub fn handler(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let cfg = &mut ctx.accounts.config;
+    cfg.pending_admin = new_admin;
+
+    emit!(AdminProposed {
+        admin: cfg.admin,
+        pending_admin: new_admin,
+    });
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use crate::errors::ErrorCode;
+use crate::state::{
+    BridgeConfig, BridgeRequest, CircuitRegistry, CircuitVersion, RequestStatus,
+    CIRCUIT_PLAN_PAYOUT, PAUSE_DEPOSITS,
+};
+use crate::{SignerAccount, ID, ID_CONST};
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+/// Standalone attestation for an existing request (e.g. one created with `init_request`):
+/// stores the client's destination ciphertext (`DestWords`) and queues `plan_payout` on it.
+/// The result lands on the request through `plan_payout_callback`, like a deposit's.
+/// Refused while a `plan_payout` is in flight and, like deposits, while deposits are paused.
+#[queue_computation_accounts("plan_payout", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, request_id: u64)]
+pub struct AttestDestination<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_DEPOSITS) @ ErrorCode::DepositsPaused
+    )]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"request", payer.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, SignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    #[account(mut, address = derive_mempool_pda!())]
+    /// CHECK: validated by Arcium
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(mut, address = derive_execpool_pda!())]
+    /// CHECK: validated by Arcium
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(mut, address = derive_comp_pda!(computation_offset))]
+    /// CHECK: validated by Arcium
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"circuit", &[CIRCUIT_PLAN_PAYOUT]], bump = circuit_registry.bump)]
+    pub circuit_registry: Box<Account<'info, CircuitRegistry>>,
+
+    /// Active version; handed to the callback so it accepts this comp-def
+    #[account(
+        seeds = [b"circuit_version", &[CIRCUIT_PLAN_PAYOUT], &circuit_registry.active_version.to_le_bytes()],
+        bump = circuit_version.bump
+    )]
+    pub circuit_version: Box<Account<'info, CircuitVersion>>,
+
+    #[account(address = derive_comp_def_pda!(circuit_version.comp_def_offset))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<AttestDestination>,
+    computation_offset: u64,
+    _request_id: u64,
+    // DestWords, encrypted by the client (same layout as initiate_bridge)
+    client_pubkey: [u8; 32],
+    nonce: [u8; 16],
+    destination_ct0: [u8; 32],
+    destination_ct1: [u8; 32],
+    destination_ct2: [u8; 32],
+    destination_ct3: [u8; 32],
+) -> Result<()> {
+    require!(client_pubkey != [0u8; 32], ErrorCode::InvalidClientPubkey);
+    let req = &mut ctx.accounts.request_pda;
+
+    // Only a request with nothing in flight: never given a ciphertext (`init_request`) or
+    // whose last attempt aborted. Replacing the ciphertext under a running plan_payout would
+    // let its callback attest a destination the MPC never checked.
+    match req.status {
+        RequestStatus::Pending => {
+            require!(!req.has_destination(), ErrorCode::AttestationInFlight)
+        }
+        RequestStatus::AttestationFailed => req.transition(RequestStatus::Pending)?,
+        _ => return err!(ErrorCode::InvalidStatusTransition),
+    }
+
+    let nonce_u128 = u128::from_le_bytes(nonce);
+    req.client_pubkey = client_pubkey;
+    req.nonce_le = nonce_u128;
+    req.dest_ct_w0 = destination_ct0;
+    req.dest_ct_w1 = destination_ct1;
+    req.dest_ct_w2 = destination_ct2;
+    req.dest_ct_w3 = destination_ct3;
+
+    let args = vec![
+        Argument::ArcisPubkey(client_pubkey),
+        Argument::PlaintextU128(nonce_u128),
+        Argument::EncryptedU64(destination_ct0),
+        Argument::EncryptedU64(destination_ct1),
+        Argument::EncryptedU64(destination_ct2),
+        Argument::EncryptedU64(destination_ct3),
+    ];
+    let callback = super::callback::PlanPayoutCallback::callback_ix(&[
+        CallbackAccount {
+            pubkey: req.key(),
+            is_writable: true,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.circuit_version.key(),
+            is_writable: false,
+        },
+    ]);
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+    queue_computation(ctx.accounts, computation_offset, args, None, vec![callback])
+}
@@ -0,0 +1,63 @@
+use crate::errors::ErrorCode;
+use crate::events::AttesterAdded;
+use crate::state::{AttesterSet, BridgeConfig, SignatureScheme, MAX_ATTESTERS};
+use anchor_lang::prelude::*;
+use core::mem::size_of;
+
+/// Registers an off-chain verifier key. Secp256k1 keys are EVM addresses, right-aligned
+/// in `key`.
+#[derive(Accounts)]
+pub struct AddAttester<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + size_of::<AttesterSet>(),
+        seeds = [b"attesters"],
+        bump
+    )]
+    pub attester_set: Box<Account<'info, AttesterSet>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddAttester>, scheme: SignatureScheme, key: [u8; 32]) -> Result<()> {
+    let set = &mut ctx.accounts.attester_set;
+    set.bump = ctx.bumps.attester_set;
+
+    match scheme {
+        SignatureScheme::Secp256k1 => {
+            let address = AttesterSet::evm_address(&key)?;
+            require!(
+                set.evm_index(&address).is_none(),
+                ErrorCode::AttesterAlreadyExists
+            );
+            let count = set.evm_count as usize;
+            require!(count < MAX_ATTESTERS, ErrorCode::TooManyAttesters);
+            set.evm[count] = address;
+            set.evm_count += 1;
+        }
+        SignatureScheme::Ed25519 => {
+            require!(
+                set.ed25519_index(&key).is_none(),
+                ErrorCode::AttesterAlreadyExists
+            );
+            let count = set.ed25519_count as usize;
+            require!(count < MAX_ATTESTERS, ErrorCode::TooManyAttesters);
+            set.ed25519[count] = Pubkey::new_from_array(key);
+            set.ed25519_count += 1;
+        }
+    }
+
+    emit!(AttesterAdded { scheme, key });
+    Ok(())
+}
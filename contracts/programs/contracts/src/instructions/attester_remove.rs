@@ -0,0 +1,45 @@
+use crate::errors::ErrorCode;
+use crate::events::AttesterRemoved;
+use crate::state::{AttesterSet, BridgeConfig, SignatureScheme};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveAttester<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(mut, seeds = [b"attesters"], bump = attester_set.bump)]
+    pub attester_set: Box<Account<'info, AttesterSet>>,
+}
+
+pub fn handler(ctx: Context<RemoveAttester>, scheme: SignatureScheme, key: [u8; 32]) -> Result<()> {
+    let set = &mut ctx.accounts.attester_set;
+
+    // Swap-remove: order of the set is not meaningful
+    match scheme {
+        SignatureScheme::Secp256k1 => {
+            let address = AttesterSet::evm_address(&key)?;
+            let idx = set.evm_index(&address).ok_or(ErrorCode::AttesterNotFound)?;
+            let last = set.evm_count as usize - 1;
+            set.evm[idx] = set.evm[last];
+            set.evm[last] = [0u8; 20];
+            set.evm_count -= 1;
+        }
+        SignatureScheme::Ed25519 => {
+            let idx = set.ed25519_index(&key).ok_or(ErrorCode::AttesterNotFound)?;
+            let last = set.ed25519_count as usize - 1;
+            set.ed25519[idx] = set.ed25519[last];
+            set.ed25519[last] = Pubkey::default();
+            set.ed25519_count -= 1;
+        }
+    }
+
+    emit!(AttesterRemoved { scheme, key });
+    Ok(())
+}
@@ -1,6 +1,5 @@
-use crate::constants::COMP_DEF_OFFSET_PLAN_PAYOUT;
-use crate::errors::ErrorCode;
-use crate::events::AttestationQueued;
+use crate::events::{AttestationFailed, AttestationQueued};
+use crate::state::{BridgeRequest, CircuitVersion, RequestStatus, CIRCUIT_PLAN_PAYOUT};
 
 use crate::ID_CONST;
 use anchor_lang::prelude::*;
@@ -11,26 +10,64 @@ use arcium_anchor::prelude::*;
 pub struct PlanPayoutCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
 
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PLAN_PAYOUT))]
+    #[account(address = derive_comp_def_pda!(circuit_version.comp_def_offset))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
 
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
     /// CHECK: validated by constraint
     pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Request the computation was queued for (passed as a writable callback account)
+    #[account(
+        mut,
+        seeds = [b"request", request_pda.payer.as_ref(), &request_pda.request_id.to_le_bytes()],
+        bump = request_pda.bump
+    )]
+    pub request_pda: Account<'info, BridgeRequest>,
+
+    /// Version the computation was queued under, so in-flight computations survive a
+    /// `set_active_circuit_version` (read-only callback account)
+    #[account(
+        seeds = [b"circuit_version", &[CIRCUIT_PLAN_PAYOUT], &circuit_version.version.to_le_bytes()],
+        bump = circuit_version.bump
+    )]
+    pub circuit_version: Account<'info, CircuitVersion>,
 }
 
 pub fn handler(
-    _ctx: Context<PlanPayoutCallback>,
+    ctx: Context<PlanPayoutCallback>,
     output: ComputationOutputs<PlanPayoutOutput>,
 ) -> Result<()> {
+    let req = &mut ctx.accounts.request_pda;
+
+    // Refunded while the MPC was running: nothing to record
+    if req.status != RequestStatus::Pending {
+        msg!("plan_payout:skip status={:?}", req.status);
+        return Ok(());
+    }
+
     // The output wrapper from the MXE comes as { field_0 }
     let o = match output {
         ComputationOutputs::Success(PlanPayoutOutput { field_0 }) => field_0,
-        _ => return Err(ErrorCode::AbortedComputation.into()),
+        _ => {
+            // Ok (not Err) so the failure is persisted: requeue_plan_payout or refund
+            req.transition(RequestStatus::AttestationFailed)?;
+            req.compute_failures = req.compute_failures.saturating_add(1);
+            emit!(AttestationFailed {
+                request_id: req.request_id,
+                payer: req.payer,
+            });
+            return Ok(());
+        }
     };
 
+    req.transition(RequestStatus::Attested)?;
+    req.attested_nonce = o.nonce;
+
     emit!(AttestationQueued {
-        nonce: o.nonce.to_le_bytes()
+        nonce: o.nonce.to_le_bytes(),
+        request_id: req.request_id,
+        payer: req.payer,
     });
     Ok(())
 }
@@ -0,0 +1,139 @@
+use crate::errors::ErrorCode;
+use crate::events::RequestCancelled;
+use crate::instructions::claim_bridge::{take_bond, BOND_VAULT_SEED};
+use crate::instructions::refund::return_escrow;
+use crate::state::{BridgeConfig, BridgeRequest, RequestStatus};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// Admin cancel of an SPL request: the locked amount and fee go back to the payer with no
+/// timeout, and a claimed (frozen) request returns the solver's whole bond without slashing.
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct CancelRequest<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    /// Original depositor (part of the request seeds); receives the escrow rent and gas fee
+    /// CHECK: bound by the request seeds
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"request", payer.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump,
+        constraint = request_pda.token_mint == mint.key() @ ErrorCode::InvalidMint,
+        constraint = !request_pda.legacy_escrow @ ErrorCode::LegacyEscrow
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// Escrow of this request (ATA owned by the request PDA); closed after the cancel
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = request_pda,
+    )]
+    pub escrow_token: Box<Account<'info, TokenAccount>>,
+
+    /// Payer's token account receiving the locked amount and fee
+    #[account(
+        mut,
+        constraint = payer_token.mint == mint.key() @ ErrorCode::InvalidMint,
+        constraint = payer_token.owner == payer.key() @ ErrorCode::InvalidOwner
+    )]
+    pub payer_token: Box<Account<'info, TokenAccount>>,
+
+    /// Bond vault of the request; required when a claim is still bonded
+    /// CHECK: program-owned, no data; validated by seeds
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, request_pda.key().as_ref()],
+        bump = request_pda.bond_bump
+    )]
+    pub bond_vault: Option<UncheckedAccount<'info>>,
+
+    /// Solver of the frozen claim; gets the bond back. Required with `bond_vault`
+    /// CHECK: address validated against request_pda.solver
+    #[account(mut, address = request_pda.solver @ ErrorCode::InvalidOwner)]
+    pub solver: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Returns a frozen claim's bond to its solver in full (the cancel is not the solver's
+/// fault, so nothing is slashed) and clears the claim. Shared by both cancel paths.
+pub(crate) fn return_bond<'info>(
+    req: &mut BridgeRequest,
+    bond_vault: Option<AccountInfo<'info>>,
+    solver: Option<AccountInfo<'info>>,
+) -> Result<u64> {
+    let bond = req.bond_lamports;
+    if bond == 0 {
+        return Ok(0);
+    }
+    require!(req.bond_bump != 0, ErrorCode::LegacyBondVault);
+    let (Some(vault), Some(solver)) = (bond_vault, solver) else {
+        return err!(ErrorCode::ClaimAccountsRequired);
+    };
+
+    take_bond(
+        &vault,
+        bond,
+        Rent::get()?.minimum_balance(0),
+        ErrorCode::MathOverflow,
+    )?;
+    **solver.try_borrow_mut_lamports()? += bond;
+
+    req.solver = Pubkey::default();
+    req.claim_deadline = 0;
+    req.bond_lamports = 0;
+    Ok(bond)
+}
+
+pub fn handler(ctx: Context<CancelRequest>, request_id: u64) -> Result<()> {
+    let req = &mut ctx.accounts.request_pda;
+    req.transition(RequestStatus::Cancelled)?;
+    req.finalized_at = Clock::get()?.unix_timestamp;
+
+    let bond_returned = return_bond(
+        req,
+        ctx.accounts
+            .bond_vault
+            .as_ref()
+            .map(|a| a.to_account_info()),
+        ctx.accounts.solver.as_ref().map(|a| a.to_account_info()),
+    )?;
+
+    let amount = req
+        .amount_locked
+        .checked_add(req.fee_locked)
+        .ok_or(ErrorCode::MathOverflow)?;
+    return_escrow(
+        req,
+        &ctx.accounts.payer.to_account_info(),
+        &mut ctx.accounts.escrow_token,
+        &ctx.accounts.mint,
+        &ctx.accounts.payer_token.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        amount,
+    )?;
+
+    emit!(RequestCancelled {
+        request_id,
+        payer: ctx.accounts.payer.key(),
+        token_mint: ctx.accounts.mint.key(),
+        amount,
+        bond_returned,
+    });
+    Ok(())
+}
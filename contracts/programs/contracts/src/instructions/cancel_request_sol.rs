@@ -0,0 +1,98 @@
+use crate::errors::ErrorCode;
+use crate::events::RequestCancelled;
+use crate::instructions::cancel_request::return_bond;
+use crate::instructions::claim_bridge::BOND_VAULT_SEED;
+use crate::instructions::refund_sol::return_escrow_sol;
+use crate::state::{BridgeConfig, BridgeRequest, RequestStatus};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{spl_token, Token, TokenAccount};
+
+/// Admin cancel of a SOL request: closing the request's WSOL escrow to the payer unwraps
+/// the locked amount and fee. A frozen claim's bond goes back to its solver unslashed.
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct CancelRequestSol<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    /// Original depositor (part of the request seeds); receives native SOL
+    /// CHECK: bound by the request seeds
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"request", payer.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump,
+        constraint = request_pda.token_mint == spl_token::native_mint::id() @ ErrorCode::InvalidMint,
+        constraint = !request_pda.legacy_escrow @ ErrorCode::LegacyEscrow
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    /// WSOL escrow of this request (ATA owned by the request PDA)
+    #[account(
+        mut,
+        associated_token::mint = spl_token::native_mint::id(),
+        associated_token::authority = request_pda,
+    )]
+    pub escrow_wsol: Box<Account<'info, TokenAccount>>,
+
+    /// Bond vault of the request; required when a claim is still bonded
+    /// CHECK: program-owned, no data; validated by seeds
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, request_pda.key().as_ref()],
+        bump = request_pda.bond_bump
+    )]
+    pub bond_vault: Option<UncheckedAccount<'info>>,
+
+    /// Solver of the frozen claim; gets the bond back. Required with `bond_vault`
+    /// CHECK: address validated against request_pda.solver
+    #[account(mut, address = request_pda.solver @ ErrorCode::InvalidOwner)]
+    pub solver: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CancelRequestSol>, request_id: u64) -> Result<()> {
+    let req = &mut ctx.accounts.request_pda;
+    req.transition(RequestStatus::Cancelled)?;
+    req.finalized_at = Clock::get()?.unix_timestamp;
+
+    let bond_returned = return_bond(
+        req,
+        ctx.accounts
+            .bond_vault
+            .as_ref()
+            .map(|a| a.to_account_info()),
+        ctx.accounts.solver.as_ref().map(|a| a.to_account_info()),
+    )?;
+
+    let amount = req
+        .amount_locked
+        .checked_add(req.fee_locked)
+        .ok_or(ErrorCode::MathOverflow)?;
+    return_escrow_sol(
+        req,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.escrow_wsol,
+        &ctx.accounts.token_program.to_account_info(),
+        amount,
+    )?;
+
+    emit!(RequestCancelled {
+        request_id,
+        payer: ctx.accounts.payer.key(),
+        token_mint: spl_token::native_mint::id(),
+        amount,
+        bond_returned,
+    });
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use crate::errors::ErrorCode;
+use crate::events::ChainConfigUpdated;
+use crate::state::{BridgeConfig, ChainConfig};
+use crate::validation::validate_chain_config;
+use anchor_lang::prelude::*;
+use core::mem::size_of;
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct InitChainConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + size_of::<ChainConfig>(),
+        seeds = [b"chain", &chain_id.to_le_bytes()],
+        bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitChainConfig>,
+    chain_id: u64,
+    enabled: bool,
+    claim_window_secs: i64,
+    min_solver_bond: u64,
+    dest_gas_fee_lamports: u64,
+) -> Result<()> {
+    let chain = &mut ctx.accounts.chain_config;
+
+    chain.chain_id = chain_id;
+    chain.enabled = enabled;
+    chain.claim_window_secs = claim_window_secs;
+    chain.min_solver_bond = min_solver_bond;
+    chain.dest_gas_fee_lamports = dest_gas_fee_lamports;
+    chain.bump = ctx.bumps.chain_config;
+    validate_chain_config(chain)?;
+
+    emit!(ChainConfigUpdated {
+        chain_id,
+        enabled,
+        claim_window_secs,
+        min_solver_bond,
+        dest_gas_fee_lamports,
+    });
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::errors::ErrorCode;
+use crate::events::ChainConfigUpdated;
+use crate::state::{BridgeConfig, ChainConfig};
+use crate::validation::validate_chain_config;
+use anchor_lang::prelude::*;
+
+/// Immediate per-chain settings. The claim window and solver bond are terms solvers rely on
+/// and go through `propose_config` / `execute_config` instead.
+#[derive(Accounts)]
+pub struct SetChainConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"chain", &chain_config.chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+}
+
+pub fn handler(
+    ctx: Context<SetChainConfig>,
+    enabled: Option<bool>,
+    dest_gas_fee_lamports: Option<u64>,
+) -> Result<()> {
+    let chain = &mut ctx.accounts.chain_config;
+
+    if let Some(v) = enabled {
+        chain.enabled = v;
+    }
+    if let Some(v) = dest_gas_fee_lamports {
+        chain.dest_gas_fee_lamports = v;
+    }
+    validate_chain_config(chain)?;
+
+    emit!(ChainConfigUpdated {
+        chain_id: chain.chain_id,
+        enabled: chain.enabled,
+        claim_window_secs: chain.claim_window_secs,
+        min_solver_bond: chain.min_solver_bond,
+        dest_gas_fee_lamports: chain.dest_gas_fee_lamports,
+    });
+    Ok(())
+}
@@ -1,16 +1,47 @@
-use crate::constants::COMP_DEF_OFFSET_RESEAL;
 use crate::errors::ErrorCode;
 use crate::events::BridgeClaimed;
-use crate::state::{BridgeConfig, BridgeRequest};
+use crate::state::{
+    BridgeConfig, BridgeRequest, ChainConfig, CircuitRegistry, CircuitVersion, ClaimReseal,
+    RequestStatus, ResealStatus, SolverEntry, CIRCUIT_RESEAL, PAUSE_CLAIMS,
+};
 use crate::{SignerAccount, ID, ID_CONST};
 
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use arcium_anchor::prelude::*;
+use core::mem::size_of;
 
-/// PDA seed for the solver's bond vault:
+/// PDA seed for the solver's bond vault: [BOND_VAULT_SEED, request_pda]
 pub const BOND_VAULT_SEED: &[u8] = b"bond";
 
+/// Takes `bond` lamports out of a bond vault (the caller credits them). The vault's
+/// rent-exempt floor (`rent_floor`) is never handed out: only lamports above it back
+/// claims, so a shared legacy vault cannot be drained by its last migrator.
+pub(crate) fn take_bond(
+    vault: &AccountInfo,
+    bond: u64,
+    rent_floor: u64,
+    underfunded: ErrorCode,
+) -> Result<()> {
+    require!(
+        vault.lamports().saturating_sub(rent_floor) >= bond,
+        underfunded
+    );
+    **vault.try_borrow_mut_lamports()? -= bond;
+    Ok(())
+}
+
+/// Splits the bond of an expired claim into (slash, refund to the solver).
+pub(crate) fn split_bond(bond: u64, slash_bps: u16) -> Result<(u64, u64)> {
+    let slash = (bond as u128)
+        .checked_mul(slash_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / 10_000;
+    let slash = u64::try_from(slash).map_err(|_| ErrorCode::MathOverflow)?;
+    let refund = bond.checked_sub(slash).ok_or(ErrorCode::MathOverflow)?;
+    Ok((slash, refund))
+}
+
 /// Claim + reseal (symmetrical with the EVM flow: give access to the solver)
 #[queue_computation_accounts("reseal_destination", solver)]
 #[derive(Accounts)]
@@ -21,9 +52,21 @@ pub struct ClaimRequest<'info> {
     pub solver: Signer<'info>,
 
     /// Global config
-    #[account(seeds = [b"config"], bump = config.bump)]
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_CLAIMS) @ ErrorCode::ClaimsPaused
+    )]
     pub config: Account<'info, BridgeConfig>,
 
+    /// Registry entry of the solver (only authorized solvers can claim)
+    #[account(
+        seeds = [b"solver", solver.key().as_ref()],
+        bump = solver_entry.bump,
+        constraint = solver_entry.solver == solver.key() @ ErrorCode::SolverNotAuthorized
+    )]
+    pub solver_entry: Box<Account<'info, SolverEntry>>,
+
     /// BridgeRequest (Variant B: seeds include external owner)
     #[account(
         mut,
@@ -32,21 +75,38 @@ pub struct ClaimRequest<'info> {
     )]
     pub request_pda: Account<'info, BridgeRequest>,
 
+    /// Destination chain of the request (bond floor)
+    #[account(
+        seeds = [b"chain", &request_pda.dest_chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Box<Account<'info, ChainConfig>>,
+
     /// Only for seeds (Variant B)
     /// CHECK: seeds-only
     pub request_owner: UncheckedAccount<'info>,
 
-    /// Vault (System-owned PDA) that holds the bond (no data)
-    /// CHECK: system-owned, no data (space = 0)
+    /// Vault PDA that holds the bond (no data), one per request
+    /// CHECK: program-owned, no data (space = 0)
     #[account(
         init_if_needed,
         payer = solver,
         space = 0,
-        seeds = [BOND_VAULT_SEED, &request_id.to_le_bytes()],
+        seeds = [BOND_VAULT_SEED, request_pda.key().as_ref()],
         bump
     )]
     pub bond_vault: UncheckedAccount<'info>,
 
+    /// Receives the resealed destination (reset on every claim)
+    #[account(
+        init_if_needed,
+        payer = solver,
+        space = 8 + size_of::<ClaimReseal>(),
+        seeds = [b"reseal", request_pda.key().as_ref()],
+        bump
+    )]
+    pub claim_reseal: Box<Account<'info, ClaimReseal>>,
+
     // ---- Arcium (reducimos stack) ----
     #[account(
         init_if_needed,
@@ -74,7 +134,17 @@ pub struct ClaimRequest<'info> {
     /// CHECK: validada por constraint (no necesitamos datos)
     pub computation_account: UncheckedAccount<'info>,
 
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESEAL))]
+    #[account(seeds = [b"circuit", &[CIRCUIT_RESEAL]], bump = circuit_registry.bump)]
+    pub circuit_registry: Box<Account<'info, CircuitRegistry>>,
+
+    /// Active version; handed to the callback so it accepts this comp-def
+    #[account(
+        seeds = [b"circuit_version", &[CIRCUIT_RESEAL], &circuit_registry.active_version.to_le_bytes()],
+        bump = circuit_version.bump
+    )]
+    pub circuit_version: Box<Account<'info, CircuitVersion>>,
+
+    #[account(address = derive_comp_def_pda!(circuit_version.comp_def_offset))]
     pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
 
     #[account(mut, address = derive_cluster_pda!(mxe_account))]
@@ -100,21 +170,31 @@ pub fn handler(
     msg!("claim:start");
 
     let cfg = &ctx.accounts.config;
+    let chain = &ctx.accounts.chain_config;
     let req = &mut ctx.accounts.request_pda;
     let now = Clock::get()?.unix_timestamp;
     msg!(
         "claim:cfg ok; min_bond={}, window={}",
-        cfg.min_solver_bond,
-        cfg.claim_window_secs
+        cfg.min_solver_bond.max(chain.min_solver_bond),
+        req.claim_window_secs
     );
 
     // --- Checks (paridad EVM) ---
-    require!(!req.finalized, ErrorCode::AlreadyFinalized);
-    if req.claimed {
-        require!(now > req.claim_deadline, ErrorCode::ActiveClaim);
-    }
+    // The destination is resealed only to the key registered for this solver
+    require!(
+        solver_x25519 == ctx.accounts.solver_entry.x25519,
+        ErrorCode::SolverX25519Mismatch
+    );
+
+    // Only requests the MPC accepted can be claimed; an expired claim must go through
+    // `release_expired_claim` first
+    require!(
+        req.status == RequestStatus::Attested,
+        ErrorCode::RequestNotAttested
+    );
+    req.transition(RequestStatus::Claimed)?;
 
-    let min_bond = cfg.min_solver_bond;
+    let min_bond = cfg.min_solver_bond.max(chain.min_solver_bond);
     require!(min_bond > 0, ErrorCode::BondTooLow);
 
     // --- Transferir bond al vault (System) ---
@@ -131,12 +211,33 @@ pub fn handler(
     msg!("claim:bond transfer ok");
 
     // --- Actualizar request ---
-    req.claimed = true;
     req.solver = ctx.accounts.solver.key();
     req.claim_deadline = now
-        .checked_add(cfg.claim_window_secs)
+        .checked_add(req.claim_window_secs)
         .ok_or(ErrorCode::MathOverflow)?;
     req.bond_lamports = min_bond;
+    req.bond_bump = ctx.bumps.bond_vault;
+    // First claim created the vault and the reseal: their rent goes back to this solver
+    if req.bond_rent_payer == Pubkey::default() {
+        req.bond_rent_payer = req.solver;
+    }
+    req.ever_claimed = true;
+
+    let reseal = &mut ctx.accounts.claim_reseal;
+    if reseal.request == Pubkey::default() {
+        reseal.rent_payer = req.solver;
+    }
+    reseal.request = req.key();
+    reseal.solver = req.solver;
+    reseal.solver_x25519 = solver_x25519;
+    reseal.status = ResealStatus::Queued;
+    reseal.nonce = 0;
+    reseal.ct_w0 = [0u8; 32];
+    reseal.ct_w1 = [0u8; 32];
+    reseal.ct_w2 = [0u8; 32];
+    reseal.ct_w3 = [0u8; 32];
+    reseal.resealed_at = 0;
+    reseal.bump = ctx.bumps.claim_reseal;
 
     // --- Evento público ---
     emit!(BridgeClaimed {
@@ -159,7 +260,27 @@ pub fn handler(
         Argument::EncryptedU64(req.dest_ct_w3),
     ];
 
-    queue_computation(ctx.accounts, computation_offset_reseal, args, None, vec![])?;
+    let callback = super::reseal_callback::ResealDestinationCallback::callback_ix(&[
+        CallbackAccount {
+            pubkey: req.key(),
+            is_writable: true,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.claim_reseal.key(),
+            is_writable: true,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.circuit_version.key(),
+            is_writable: false,
+        },
+    ]);
+    queue_computation(
+        ctx.accounts,
+        computation_offset_reseal,
+        args,
+        None,
+        vec![callback],
+    )?;
     msg!(
         "claim:queue_reseal ok (offset={})",
         computation_offset_reseal
@@ -167,3 +288,91 @@ pub fn handler(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request_pda(payer: &Pubkey, request_id: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"request", payer.as_ref(), &request_id.to_le_bytes()],
+            &crate::ID,
+        )
+        .0
+    }
+
+    fn bond_vault(request: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[BOND_VAULT_SEED, request.as_ref()], &crate::ID).0
+    }
+
+    fn legacy_bond_vault(request_id: u64) -> Pubkey {
+        Pubkey::find_program_address(&[BOND_VAULT_SEED, &request_id.to_le_bytes()], &crate::ID).0
+    }
+
+    // Bond accounting: vault balances as the handlers leave them (claim = rent + bond in)
+
+    const RENT: u64 = 890_880; // Rent::minimum_balance(0)
+    const BOND_A: u64 = 20_000_000;
+    const BOND_B: u64 = 35_000_000;
+
+    fn vault_info<'a>(key: &'a Pubkey, lamports: &'a mut u64) -> AccountInfo<'a> {
+        AccountInfo::new(key, false, true, lamports, &mut [], &crate::ID, false, 0)
+    }
+
+    fn err(r: Result<()>, code: ErrorCode) -> bool {
+        r.err() == Some(code.into())
+    }
+
+    #[test]
+    fn same_request_id_bonds_stay_isolated_through_settle_and_release() {
+        let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (key_a, key_b) = (
+            bond_vault(&request_pda(&alice, 1)),
+            bond_vault(&request_pda(&bob, 1)),
+        );
+        let (mut lamports_a, mut lamports_b) = (RENT + BOND_A, RENT + BOND_B);
+        let vault_a = vault_info(&key_a, &mut lamports_a);
+        let vault_b = vault_info(&key_b, &mut lamports_b);
+
+        // Alice's request settles: only her vault pays out, down to its rent
+        take_bond(&vault_a, BOND_A, RENT, ErrorCode::MathOverflow).unwrap();
+        assert_eq!(vault_a.lamports(), RENT);
+        assert_eq!(vault_b.lamports(), RENT + BOND_B);
+        // and paying her bond twice never reaches into Bob's
+        assert!(err(
+            take_bond(&vault_a, BOND_A, RENT, ErrorCode::MathOverflow),
+            ErrorCode::MathOverflow
+        ));
+
+        // Bob's claim expires: slash + refund add up to exactly his bond
+        let (slash, refund) = split_bond(BOND_B, 5_000).unwrap();
+        assert_eq!(slash + refund, BOND_B);
+        take_bond(&vault_b, BOND_B, RENT, ErrorCode::MathOverflow).unwrap();
+        assert_eq!(vault_b.lamports(), RENT);
+        assert_eq!(vault_a.lamports(), RENT);
+    }
+
+    #[test]
+    fn legacy_vault_migrates_each_bond_and_keeps_its_rent() {
+        let key = legacy_bond_vault(1);
+        let mut lamports = RENT + BOND_A + BOND_B; // two payers' claims on request_id 1
+        let legacy = vault_info(&key, &mut lamports);
+        let under = ErrorCode::LegacyBondVaultUnderfunded;
+
+        take_bond(&legacy, BOND_A, RENT, under).unwrap();
+        assert_eq!(legacy.lamports(), RENT + BOND_B);
+        // the last migrator gets its bond, not the shared account's rent
+        assert!(err(take_bond(&legacy, BOND_B + 1, RENT, under), under));
+        take_bond(&legacy, BOND_B, RENT, under).unwrap();
+        assert_eq!(legacy.lamports(), RENT);
+        assert!(err(take_bond(&legacy, 1, RENT, under), under));
+    }
+
+    #[test]
+    fn split_bond_bounds() {
+        assert_eq!(split_bond(BOND_A, 0).unwrap(), (0, BOND_A));
+        assert_eq!(split_bond(BOND_A, 10_000).unwrap(), (BOND_A, 0));
+        assert_eq!(split_bond(3, 5_000).unwrap(), (1, 2)); // slash rounds down
+        assert_eq!(split_bond(u64::MAX, 10_000).unwrap(), (u64::MAX, 0));
+    }
+}
@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self as token, CloseAccount, Mint, Token, TokenAccount, TransferChecked};
+
+use crate::errors::ErrorCode;
+use crate::events::BridgeRequestClosed;
+use crate::instructions::claim_bridge::BOND_VAULT_SEED;
+use crate::state::{BridgeConfig, BridgeRequest, ClaimReseal, RequestStatus};
+use crate::ID;
+
+/// Closes a Settled/Refunded/Cancelled request once `close_grace_secs` have passed since it
+/// finished. Permissionless. Rent goes back to whoever paid it: the request PDA and its
+/// escrow to the payer, the bond vault to `request_pda.bond_rent_payer` and the claim
+/// reseal to its `rent_payer` (the solvers whose claims created them). Tokens left in the
+/// escrow are swept to the payer first. Votes on the request's settlement are not closed
+/// here; they are bound to `created_at` (see `crate::digest`) and expire.
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct CloseRequest<'info> {
+    /// Anyone can crank the close
+    pub caller: Signer<'info>,
+
+    /// Global config (close grace period)
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"request", payer.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    /// Original payer (part of the request seeds); receives the request and escrow rent
+    /// CHECK: bound by the request seeds
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// Bond vault of this request (may not exist if it was never claimed)
+    /// CHECK: validated by seeds; only drained when owned by this program
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, request_pda.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: UncheckedAccount<'info>,
+
+    /// Receives the bond vault's rent (payer if the vault predates the record)
+    /// CHECK: address validated against the request
+    #[account(mut, address = request_pda.bond_rent_recipient() @ ErrorCode::InvalidOwner)]
+    pub bond_rent_payer: UncheckedAccount<'info>,
+
+    /// Reseal of the last claim; required once the request was ever claimed
+    #[account(
+        mut,
+        seeds = [b"reseal", request_pda.key().as_ref()],
+        bump = claim_reseal.bump
+    )]
+    pub claim_reseal: Option<Box<Account<'info, ClaimReseal>>>,
+
+    /// Receives the claim reseal's rent; required with `claim_reseal`
+    /// CHECK: validated at runtime against claim_reseal.rent_payer
+    #[account(mut)]
+    pub reseal_rent_payer: Option<UncheckedAccount<'info>>,
+
+    /// Escrow ATA of this request (already closed for SOL refunds)
+    /// CHECK: address derived from the request; emptied to the payer, then closed
+    #[account(
+        mut,
+        address = get_associated_token_address(&request_pda.key(), &request_pda.token_mint)
+    )]
+    pub escrow_token: UncheckedAccount<'info>,
+
+    #[account(address = request_pda.token_mint @ ErrorCode::InvalidMint)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// Payer's token account; required only to sweep tokens left in the escrow
+    #[account(
+        mut,
+        constraint = payer_token.mint == request_pda.token_mint @ ErrorCode::InvalidMint,
+        constraint = payer_token.owner == payer.key() @ ErrorCode::InvalidOwner
+    )]
+    pub payer_token: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CloseRequest>, request_id: u64) -> Result<()> {
+    let cfg = &ctx.accounts.config;
+    let req = &ctx.accounts.request_pda;
+
+    // --- Guards ---
+    require!(
+        matches!(
+            req.status,
+            RequestStatus::Settled | RequestStatus::Refunded | RequestStatus::Cancelled
+        ),
+        ErrorCode::RequestNotFinalized
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let closable_at = req
+        .finalized_at
+        .checked_add(cfg.close_grace_secs)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(now >= closable_at, ErrorCode::CloseGracePeriodActive);
+
+    // --- Claim reseal: back to the solver that paid for it ---
+    match &ctx.accounts.claim_reseal {
+        Some(reseal) => {
+            let rent_payer = ctx
+                .accounts
+                .reseal_rent_payer
+                .as_ref()
+                .ok_or(ErrorCode::ClaimAccountsRequired)?;
+            require_keys_eq!(rent_payer.key(), reseal.rent_payer, ErrorCode::InvalidOwner);
+            reseal.close(rent_payer.to_account_info())?;
+        }
+        None => require!(!req.ever_claimed, ErrorCode::ClaimAccountsRequired),
+    }
+
+    // --- Sweep bond vault dust (its rent) to whoever created the vault ---
+    let vault = ctx.accounts.bond_vault.to_account_info();
+    let dust = if vault.owner == &ID {
+        vault.lamports()
+    } else {
+        0
+    };
+    if dust > 0 {
+        **vault.try_borrow_mut_lamports()? -= dust;
+        **ctx
+            .accounts
+            .bond_rent_payer
+            .to_account_info()
+            .try_borrow_mut_lamports()? += dust;
+    }
+
+    // --- Empty the escrow to the payer if it is still open, then close it ---
+    let escrow = ctx.accounts.escrow_token.to_account_info();
+    if escrow.owner == &token::ID && !escrow.data_is_empty() {
+        let escrow_amount = {
+            let data = escrow.try_borrow_data()?;
+            TokenAccount::try_deserialize(&mut &data[..])?.amount
+        };
+        let payer_key = ctx.accounts.payer.key();
+        let id_bytes = request_id.to_le_bytes();
+        let bump = [req.bump];
+        let signer_seeds: &[&[u8]] = &[b"request", payer_key.as_ref(), &id_bytes, &bump];
+
+        if escrow_amount > 0 {
+            let payer_token = ctx
+                .accounts
+                .payer_token
+                .as_ref()
+                .ok_or(ErrorCode::EscrowNotEmpty)?;
+            token::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: escrow.clone(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: payer_token.to_account_info(),
+                        authority: req.to_account_info(),
+                    },
+                    &[signer_seeds],
+                ),
+                escrow_amount,
+                ctx.accounts.mint.decimals,
+            )?;
+        }
+
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: escrow,
+                destination: ctx.accounts.payer.to_account_info(),
+                authority: req.to_account_info(),
+            },
+            &[signer_seeds],
+        ))?;
+    }
+
+    // The request PDA itself is closed to `payer` by the `close` constraint
+    emit!(BridgeRequestClosed {
+        request_id,
+        payer: ctx.accounts.payer.key(),
+        bond_dust: dust,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::errors::ErrorCode;
+use crate::events::FeesCollected;
+use crate::state::{BridgeConfig, FeeVault};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self as token, Mint, Token, TokenAccount, TransferChecked};
+
+/// Sweeps the protocol fees of one mint to `recipient_token` (EVM `collectFees(token, recipient)`).
+#[derive(Accounts)]
+pub struct CollectFees<'info> {
+    /// Admin or fee collector
+    #[account(
+        constraint = authority.key() == config.admin
+            || authority.key() == config.fee_collector @ ErrorCode::OnlyFeeCollector
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Box<Account<'info, FeeVault>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = fee_vault,
+    )]
+    pub fee_vault_token: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = recipient_token.mint == mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub recipient_token: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CollectFees>) -> Result<()> {
+    let vault = &mut ctx.accounts.fee_vault;
+    let fees = vault.collected_fees;
+    require!(fees > 0, ErrorCode::NoFeesToCollect);
+
+    vault.collected_fees = 0;
+
+    let mint_key = ctx.accounts.mint.key();
+    let bump = [vault.bump];
+    let signer_seeds: &[&[u8]] = &[b"fee_vault", mint_key.as_ref(), &bump];
+
+    token::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.fee_vault_token.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.recipient_token.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        fees,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(FeesCollected {
+        token: mint_key,
+        amount: fees,
+        recipient: ctx.accounts.recipient_token.owner,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::errors::ErrorCode;
+use crate::events::ConfigChangeCancelled;
+use crate::state::{BridgeConfig, PendingConfigChange};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"pending_config"],
+        bump = pending_config.bump
+    )]
+    pub pending_config: Account<'info, PendingConfigChange>,
+}
+
+pub fn handler(ctx: Context<CancelConfig>) -> Result<()> {
+    emit!(ConfigChangeCancelled {
+        eta: ctx.accounts.pending_config.eta,
+    });
+    Ok(())
+}
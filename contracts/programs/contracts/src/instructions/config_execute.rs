@@ -0,0 +1,78 @@
+use crate::errors::ErrorCode;
+use crate::events::{ChainConfigUpdated, ConfigChangeExecuted};
+use crate::state::{BridgeConfig, ChainConfig, PendingConfigChange};
+use crate::validation::{validate_chain_config, validate_config};
+use anchor_lang::prelude::*;
+
+/// Applies the pending change once its timelock has elapsed. Open requests keep the
+/// claim window / slash / protocol fee they snapshotted at deposit; a chain's bond applies
+/// to the next claim.
+#[derive(Accounts)]
+pub struct ExecuteConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"pending_config"],
+        bump = pending_config.bump
+    )]
+    pub pending_config: Account<'info, PendingConfigChange>,
+
+    /// Chain named by the pending change; required when it has `chain_*` fields
+    #[account(
+        mut,
+        seeds = [b"chain", &pending_config.chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Option<Account<'info, ChainConfig>>,
+}
+
+pub fn handler(ctx: Context<ExecuteConfig>) -> Result<()> {
+    let pending = &ctx.accounts.pending_config;
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= pending.eta, ErrorCode::TimelockNotElapsed);
+
+    let cfg = &mut ctx.accounts.config;
+    pending.apply_to(cfg);
+    validate_config(cfg)?;
+
+    if pending.changes_chain() {
+        let chain = ctx
+            .accounts
+            .chain_config
+            .as_mut()
+            .ok_or(ErrorCode::ChainConfigRequired)?;
+        pending.apply_to_chain(chain);
+        validate_chain_config(chain)?;
+
+        emit!(ChainConfigUpdated {
+            chain_id: chain.chain_id,
+            enabled: chain.enabled,
+            claim_window_secs: chain.claim_window_secs,
+            min_solver_bond: chain.min_solver_bond,
+            dest_gas_fee_lamports: chain.dest_gas_fee_lamports,
+        });
+    }
+
+    emit!(ConfigChangeExecuted {
+        claim_window_secs: pending.claim_window_secs,
+        min_solver_bond: pending.min_solver_bond,
+        slash_bps: pending.slash_bps,
+        protocol_fee_bps: pending.protocol_fee_bps,
+        config_timelock_secs: pending.config_timelock_secs,
+        chain_id: pending.chain_id,
+        chain_claim_window_secs: pending.chain_claim_window_secs,
+        chain_min_solver_bond: pending.chain_min_solver_bond,
+    });
+    Ok(())
+}
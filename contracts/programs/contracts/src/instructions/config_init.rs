@@ -1,6 +1,6 @@
-use crate::state::BridgeConfig;
+use crate::state::{BridgeConfig, CONFIG_VERSION, DEFAULT_MAX_COMPUTE_FAILURES, MAX_RELAYERS};
+use crate::validation::validate_config;
 use anchor_lang::prelude::*;
-use core::mem::size_of;
 
 #[derive(Accounts)]
 pub struct InitConfig<'info> {
@@ -9,7 +9,7 @@ pub struct InitConfig<'info> {
     #[account(
         init,
         payer = payer,
-        space = 8 + size_of::<BridgeConfig>(),
+        space = BridgeConfig::LEN,
         seeds = [b"config"],
         bump
     )]
@@ -17,6 +17,7 @@ pub struct InitConfig<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<InitConfig>,
     fee_bps: u16,
@@ -25,16 +26,38 @@ pub fn handler(
     claim_window_secs: i64,
     min_solver_bond: u64,
     slash_bps: u16,
+    refund_timeout_secs: i64,
+    close_grace_secs: i64,
+    protocol_fee_bps: u16,
+    config_timelock_secs: i64,
 ) -> Result<()> {
     let bump = ctx.bumps.config;
     let cfg = &mut ctx.accounts.config;
-    cfg.owner = ctx.accounts.payer.key();
+    let admin = ctx.accounts.payer.key();
+    cfg.version = CONFIG_VERSION;
+    cfg.admin = admin;
     cfg.fee_bps = fee_bps;
     cfg.min_fee = min_fee;
     cfg.max_fee = max_fee;
     cfg.claim_window_secs = claim_window_secs;
     cfg.min_solver_bond = min_solver_bond;
     cfg.slash_bps = slash_bps;
+    cfg.refund_timeout_secs = refund_timeout_secs;
+    cfg.close_grace_secs = close_grace_secs;
+    cfg.protocol_fee_bps = protocol_fee_bps;
+    cfg.config_timelock_secs = config_timelock_secs;
+    // Every role starts with the admin; hand them out with set_roles / add_relayer
+    cfg.pending_admin = Pubkey::default();
+    cfg.relayers = [Pubkey::default(); MAX_RELAYERS];
+    cfg.relayers[0] = admin;
+    cfg.relayer_count = 1;
+    cfg.fee_collector = admin;
+    cfg.slash_collector = admin;
+    cfg.pauser = admin;
+    cfg.paused = 0;
     cfg.bump = bump;
+    cfg.max_compute_failures = DEFAULT_MAX_COMPUTE_FAILURES;
+    cfg.relayer_threshold = 1;
+    validate_config(cfg)?;
     Ok(())
 }
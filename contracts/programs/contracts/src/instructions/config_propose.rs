@@ -0,0 +1,97 @@
+use crate::errors::ErrorCode;
+use crate::events::ConfigChangeProposed;
+use crate::state::{BridgeConfig, ChainConfig, PendingConfigChange};
+use crate::validation::{validate_chain_config, validate_config};
+use anchor_lang::prelude::*;
+use core::mem::size_of;
+
+/// Queues a change to the terms solvers rely on; executable after `config_timelock_secs`.
+/// Fails if a change is already pending (cancel it first).
+#[derive(Accounts)]
+pub struct ProposeConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + size_of::<PendingConfigChange>(),
+        seeds = [b"pending_config"],
+        bump
+    )]
+    pub pending_config: Account<'info, PendingConfigChange>,
+
+    /// Chain whose bond / claim window change; required with the `chain_*` arguments
+    #[account(
+        seeds = [b"chain", &chain_config.chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Option<Account<'info, ChainConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeConfig>,
+    claim_window_secs: Option<i64>,
+    min_solver_bond: Option<u64>,
+    slash_bps: Option<u16>,
+    protocol_fee_bps: Option<u16>,
+    config_timelock_secs: Option<i64>,
+    chain_claim_window_secs: Option<i64>,
+    chain_min_solver_bond: Option<u64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let eta = now
+        .checked_add(ctx.accounts.config.config_timelock_secs)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let pending = &mut ctx.accounts.pending_config;
+    pending.proposer = ctx.accounts.authority.key();
+    pending.claim_window_secs = claim_window_secs;
+    pending.min_solver_bond = min_solver_bond;
+    pending.slash_bps = slash_bps;
+    pending.protocol_fee_bps = protocol_fee_bps;
+    pending.config_timelock_secs = config_timelock_secs;
+    pending.chain_claim_window_secs = chain_claim_window_secs;
+    pending.chain_min_solver_bond = chain_min_solver_bond;
+    pending.proposed_at = now;
+    pending.eta = eta;
+    pending.bump = ctx.bumps.pending_config;
+
+    // Reject now rather than after the timelock
+    let mut preview = (*ctx.accounts.config).clone();
+    pending.apply_to(&mut preview);
+    validate_config(&preview)?;
+    if pending.changes_chain() {
+        let chain = ctx
+            .accounts
+            .chain_config
+            .as_ref()
+            .ok_or(ErrorCode::ChainConfigRequired)?;
+        pending.chain_id = chain.chain_id;
+        let mut chain_preview = (**chain).clone();
+        pending.apply_to_chain(&mut chain_preview);
+        validate_chain_config(&chain_preview)?;
+    }
+
+    emit!(ConfigChangeProposed {
+        claim_window_secs,
+        min_solver_bond,
+        slash_bps,
+        protocol_fee_bps,
+        config_timelock_secs,
+        chain_id: pending.chain_id,
+        chain_claim_window_secs,
+        chain_min_solver_bond,
+        eta,
+    });
+    Ok(())
+}
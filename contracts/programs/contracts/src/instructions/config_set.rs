@@ -1,7 +1,10 @@
 use crate::errors::ErrorCode;
 use crate::state::BridgeConfig;
+use crate::validation::validate_config;
 use anchor_lang::prelude::*;
 
+/// Immediate changes only. Terms solvers rely on (claim window, bond, slash, protocol fee)
+/// go through propose_config / execute_config.
 #[derive(Accounts)]
 pub struct SetConfig<'info> {
     #[account(mut)]
@@ -10,7 +13,7 @@ pub struct SetConfig<'info> {
         mut,
         seeds = [b"config"],
         bump = config.bump,
-        constraint = config.owner == authority.key() @ ErrorCode::OnlyOwner
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
     )]
     pub config: Account<'info, BridgeConfig>,
 }
@@ -20,9 +23,9 @@ pub fn handler(
     fee_bps: Option<u16>,
     min_fee: Option<u64>,
     max_fee: Option<u64>,
-    claim_window_secs: Option<i64>,
-    min_solver_bond: Option<u64>,
-    slash_bps: Option<u16>,
+    refund_timeout_secs: Option<i64>,
+    close_grace_secs: Option<i64>,
+    max_compute_failures: Option<u8>,
 ) -> Result<()> {
     let cfg = &mut ctx.accounts.config;
 
@@ -35,15 +38,16 @@ pub fn handler(
     if let Some(v) = max_fee {
         cfg.max_fee = v;
     }
-    if let Some(v) = claim_window_secs {
-        cfg.claim_window_secs = v;
+    if let Some(v) = refund_timeout_secs {
+        cfg.refund_timeout_secs = v;
     }
-    if let Some(v) = min_solver_bond {
-        cfg.min_solver_bond = v;
+    if let Some(v) = close_grace_secs {
+        cfg.close_grace_secs = v;
     }
-    if let Some(v) = slash_bps {
-        cfg.slash_bps = v;
+    if let Some(v) = max_compute_failures {
+        cfg.max_compute_failures = v;
     }
 
+    validate_config(cfg)?;
     Ok(())
 }
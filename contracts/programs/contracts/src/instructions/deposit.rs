@@ -1,19 +1,23 @@
 // programs/contracts/src/instructions/deposit.rs
 use crate::{ID, ID_CONST};
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self as token, Mint, Token, TokenAccount, TransferChecked};
 use arcium_anchor::prelude::*;
-use core::mem::size_of;
 
 use crate::errors::ErrorCode;
 use crate::events::BridgeInitiated;
-use crate::state::{BridgeConfig, BridgeRequest};
-use crate::{constants::COMP_DEF_OFFSET_PLAN_PAYOUT, SignerAccount};
+use crate::state::{
+    BridgeConfig, BridgeRequest, ChainConfig, CircuitRegistry, CircuitVersion, RequestStatus,
+    TokenConfig, TokenRoute, CIRCUIT_PLAN_PAYOUT, PAUSE_DEPOSITS, REQUEST_VERSION,
+};
+use crate::SignerAccount;
 
 // ✅ Mantén el macro; Arcium genera los traits/validaciones del CPI
 #[queue_computation_accounts("plan_payout", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64, request_id: u64)]
+#[instruction(computation_offset: u64, request_id: u64, dest_chain_id: u64)]
 pub struct DepositAndQueue<'info> {
     // --- Signer/payer ---
     #[account(mut)]
@@ -29,28 +33,56 @@ pub struct DepositAndQueue<'info> {
 
     pub mint: Box<Account<'info, Mint>>,
 
-    #[account(
-        mut,
-        constraint = escrow_token.mint == mint.key() @ ErrorCode::InvalidMint
-    )]
-    pub escrow_token: Box<Account<'info, TokenAccount>>,
-
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
 
     // --- Global config ---
-    #[account(seeds=[b"config"], bump = config.bump)]
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_DEPOSITS) @ ErrorCode::DepositsPaused
+    )]
     pub config: Box<Account<'info, BridgeConfig>>,
 
+    // --- Per-mint rules (allowlist, limits, fees) ---
+    #[account(seeds = [b"token_config", mint.key().as_ref()], bump = token_config.bump)]
+    pub token_config: Box<Account<'info, TokenConfig>>,
+
+    // --- Destination chain (must be enabled) ---
+    #[account(
+        seeds = [b"chain", &dest_chain_id.to_le_bytes()],
+        bump = chain_config.bump,
+        constraint = chain_config.enabled @ ErrorCode::ChainDisabled
+    )]
+    pub chain_config: Box<Account<'info, ChainConfig>>,
+
+    // --- Where this mint lands on the destination chain ---
+    #[account(
+        seeds = [b"token_route", mint.key().as_ref(), &dest_chain_id.to_le_bytes()],
+        bump = token_route.bump,
+        constraint = token_route.enabled @ ErrorCode::TokenRouteDisabled
+    )]
+    pub token_route: Box<Account<'info, TokenRoute>>,
+
     // --- Request PDA ---
     #[account(
         init,
         payer = payer,
-        space = 8 + size_of::<BridgeRequest>(),
+        space = BridgeRequest::LEN,
         seeds = [b"request", payer.key().as_ref(), &request_id.to_le_bytes()],
         bump
     )]
     pub request_pda: Box<Account<'info, BridgeRequest>>,
 
+    // --- Escrow of this request only (ATA owned by the request PDA) ---
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = request_pda,
+    )]
+    pub escrow_token: Box<Account<'info, TokenAccount>>,
+
     // --- Signer PDA propio del bridge ---
     #[account(
         init_if_needed,
@@ -83,7 +115,17 @@ pub struct DepositAndQueue<'info> {
     /// CHECK: checked by the address constraint
     pub computation_account: UncheckedAccount<'info>,
 
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PLAN_PAYOUT))]
+    #[account(seeds = [b"circuit", &[CIRCUIT_PLAN_PAYOUT]], bump = circuit_registry.bump)]
+    pub circuit_registry: Box<Account<'info, CircuitRegistry>>,
+
+    /// Active version; handed to the callback so it accepts this comp-def
+    #[account(
+        seeds = [b"circuit_version", &[CIRCUIT_PLAN_PAYOUT], &circuit_registry.active_version.to_le_bytes()],
+        bump = circuit_version.bump
+    )]
+    pub circuit_version: Box<Account<'info, CircuitVersion>>,
+
+    #[account(address = derive_comp_def_pda!(circuit_version.comp_def_offset))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
 
     #[account(mut, address = derive_cluster_pda!(mxe_account))]
@@ -107,6 +149,7 @@ pub fn handler(
     ctx: Context<DepositAndQueue>,
     computation_offset: u64,
     request_id: u64,
+    dest_chain_id: u64,
     // Material de cliente
     client_pubkey: [u8; 32],
     nonce: [u8; 16],
@@ -116,20 +159,11 @@ pub fn handler(
     destination_ct3: [u8; 32],
     amount: u64,
 ) -> Result<()> {
+    require!(client_pubkey != [0u8; 32], ErrorCode::InvalidClientPubkey);
     // 0) Fees
-    let cfg = &ctx.accounts.config;
-    let mut fee = (amount as u128 * cfg.fee_bps as u128) / 10_000;
-    if fee < cfg.min_fee as u128 {
-        fee = cfg.min_fee as u128;
-    }
-    if fee > cfg.max_fee as u128 {
-        fee = cfg.max_fee as u128;
-    }
-    if fee as u64 >= amount {
-        fee = (amount / 2) as u128;
-    }
-    let fee_u64 = u64::try_from(fee).unwrap_or(u64::MAX);
-    let amount_net = amount.saturating_sub(fee_u64);
+    let (fee_u64, amount_net) = ctx.accounts.token_config.quote(amount)?;
+    let route = &ctx.accounts.token_route;
+    let expected_dest_amount = route.to_dest_amount(amount_net, ctx.accounts.mint.decimals)?;
 
     // 1) Lock SPL
     token::transfer_checked(
@@ -146,18 +180,48 @@ pub fn handler(
         ctx.accounts.mint.decimals,
     )?;
 
+    // Destination gas fee: lamports parked on the request PDA until settle/refund
+    let dest_gas_fee = ctx.accounts.chain_config.dest_gas_fee_lamports;
+    if dest_gas_fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.request_pda.to_account_info(),
+                },
+            ),
+            dest_gas_fee,
+        )?;
+    }
+
     // 2) Inicializa Request PDA
     let req = &mut ctx.accounts.request_pda;
+    req.status = RequestStatus::Pending;
+    req.version = REQUEST_VERSION;
+    req.request_id = request_id;
     req.payer = ctx.accounts.payer.key();
     req.token_mint = ctx.accounts.mint.key();
     req.amount_locked = amount_net;
     req.fee_locked = fee_u64;
+    req.token_config_version = ctx.accounts.token_config.version;
     req.created_at = Clock::get()?.unix_timestamp;
-    req.claimed = false;
     req.solver = Pubkey::default();
     req.claim_deadline = 0;
     req.bond_lamports = 0;
-    req.finalized = false;
+    req.bond_bump = 0;
+    req.finalized_at = 0;
+    req.dest_chain_id = dest_chain_id;
+    req.dest_token = route.dest_token;
+    req.expected_dest_amount = expected_dest_amount;
+    req.dest_gas_fee_lamports = dest_gas_fee;
+    req.claim_window_secs = match ctx.accounts.chain_config.claim_window_secs {
+        0 => ctx.accounts.config.claim_window_secs,
+        w => w,
+    };
+    req.refund_timeout_secs = ctx.accounts.config.refund_timeout_secs;
+    req.slash_bps = ctx.accounts.config.slash_bps;
+    req.protocol_fee_bps = ctx.accounts.config.protocol_fee_bps;
     req.bump = ctx.bumps.request_pda;
 
     let nonce_u128 = u128::from_le_bytes(nonce);
@@ -176,6 +240,10 @@ pub fn handler(
         amount_after_fee: amount_net,
         fee: fee_u64,
         ts: Clock::get()?.unix_timestamp as u64,
+        dest_chain_id,
+        dest_token: req.dest_token,
+        expected_dest_amount,
+        dest_gas_fee,
     });
 
     // 4) Queue Arcium (plan_payout)
@@ -194,7 +262,16 @@ pub fn handler(
         computation_offset,
         args,
         None,
-        vec![super::callback::PlanPayoutCallback::callback_ix(&[])],
+        vec![super::callback::PlanPayoutCallback::callback_ix(&[
+            CallbackAccount {
+                pubkey: ctx.accounts.request_pda.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.circuit_version.key(),
+                is_writable: false,
+            },
+        ])],
     )?;
 
     Ok(())
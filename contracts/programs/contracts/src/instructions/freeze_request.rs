@@ -0,0 +1,45 @@
+use crate::errors::ErrorCode;
+use crate::events::RequestFrozen;
+use crate::state::{BridgeConfig, BridgeRequest, RequestStatus};
+use anchor_lang::prelude::*;
+
+/// Holds a live request during an incident. A frozen request can no longer be attested,
+/// claimed or settled: the payer may still refund it once its timeout passes (if it was
+/// never claimed) and the admin may cancel it. Callable by the pauser or the admin.
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct FreezeRequest<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = authority.key() == config.pauser
+            || authority.key() == config.admin @ ErrorCode::OnlyPauser
+    )]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"request", request_owner.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    /// CHECK: seeds-only
+    pub request_owner: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<FreezeRequest>, request_id: u64) -> Result<()> {
+    let req = &mut ctx.accounts.request_pda;
+    let from = req.status;
+    req.transition(RequestStatus::Frozen)?;
+
+    emit!(RequestFrozen {
+        request_id,
+        payer: ctx.accounts.request_owner.key(),
+        from,
+        by: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
@@ -1,14 +1,45 @@
-use crate::constants::CIRCUIT_URL_PLAN_PAYOUT;
+use crate::constants::{CIRCUIT_HASH_PLAN_PAYOUT, COMP_DEF_OFFSET_PLAN_PAYOUT};
+use crate::errors::ErrorCode;
+use crate::state::{BridgeConfig, CircuitRegistry, CircuitVersion, CIRCUIT_PLAN_PAYOUT};
 use crate::ID;
 use anchor_lang::prelude::*;
 use arcium_anchor::prelude::*;
 use arcium_client::idl::arcium::types::{CircuitSource, OffChainCircuitSource};
+use core::mem::size_of;
+
+use super::register_circuit_version::{activate, record_version};
 
 #[init_computation_definition_accounts("plan_payout", payer)]
 #[derive(Accounts)]
 pub struct InitPlanPayoutCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + size_of::<CircuitRegistry>(),
+        seeds = [b"circuit", &[CIRCUIT_PLAN_PAYOUT]],
+        bump
+    )]
+    pub circuit_registry: Box<Account<'info, CircuitRegistry>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + size_of::<CircuitVersion>(),
+        seeds = [b"circuit_version", &[CIRCUIT_PLAN_PAYOUT], &1u32.to_le_bytes()],
+        bump
+    )]
+    pub circuit_version: Box<Account<'info, CircuitVersion>>,
+
     #[account(mut, address = derive_mxe_pda!())]
     pub mxe_account: Box<Account<'info, MXEAccount>>,
     #[account(mut)]
@@ -18,16 +49,35 @@ pub struct InitPlanPayoutCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitPlanPayoutCompDef>) -> Result<()> {
+/// `source` is where the MXE fetches `build/plan_payout.arcis`; it must serve the exact file
+/// whose hash is pinned in `circuit_hashes.rs`.
+pub fn handler(ctx: Context<InitPlanPayoutCompDef>, source: String) -> Result<()> {
+    require!(
+        CIRCUIT_HASH_PLAN_PAYOUT != [0u8; 32],
+        ErrorCode::CircuitHashNotPinned
+    );
     init_comp_def(
         ctx.accounts,
         true,
-        0,
+        0, // cu_amount: Arcium default
         Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: CIRCUIT_URL_PLAN_PAYOUT.to_string(),
-            hash: [0u8; 32],
+            source,
+            hash: CIRCUIT_HASH_PLAN_PAYOUT,
         })),
         None,
     )?;
+
+    let registry = &mut ctx.accounts.circuit_registry;
+    registry.circuit = CIRCUIT_PLAN_PAYOUT;
+    registry.bump = ctx.bumps.circuit_registry;
+    record_version(
+        registry,
+        &mut ctx.accounts.circuit_version,
+        ctx.bumps.circuit_version,
+        1,
+        COMP_DEF_OFFSET_PLAN_PAYOUT,
+        CIRCUIT_HASH_PLAN_PAYOUT,
+    )?;
+    activate(registry, &ctx.accounts.circuit_version);
     Ok(())
 }
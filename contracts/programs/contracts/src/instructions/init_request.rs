@@ -1,7 +1,6 @@
 use anchor_lang::prelude::*;
-use core::mem::size_of;
 
-use crate::state::BridgeRequest;
+use crate::state::{BridgeRequest, RequestStatus, REQUEST_VERSION};
 
 #[derive(Accounts)]
 #[instruction(request_id: u64)]
@@ -12,7 +11,7 @@ pub struct InitRequest<'info> {
     #[account(
         init,
         payer = payer,
-        space = 8 + size_of::<BridgeRequest>(),
+        space = BridgeRequest::LEN,
         seeds = [b"request", payer.key().as_ref(), &request_id.to_le_bytes()],
         bump
     )]
@@ -23,7 +22,7 @@ pub struct InitRequest<'info> {
 
 pub fn handler(
     ctx: Context<InitRequest>,
-    _request_id: u64,
+    request_id: u64,
     token_mint: Pubkey,
     amount_locked: u64,
     fee_locked: u64,
@@ -32,16 +31,32 @@ pub fn handler(
 ) -> Result<()> {
     let request = &mut ctx.accounts.request_pda;
 
+    request.status = if solver.is_some() {
+        RequestStatus::Claimed
+    } else {
+        RequestStatus::Pending
+    };
+    request.version = REQUEST_VERSION;
+    request.request_id = request_id;
     request.payer = ctx.accounts.payer.key();
     request.token_mint = token_mint;
     request.amount_locked = amount_locked;
     request.fee_locked = fee_locked;
+    request.token_config_version = 0;
     request.created_at = Clock::get()?.unix_timestamp;
-    request.claimed = solver.is_some();
     request.solver = solver.unwrap_or(Pubkey::default());
     request.claim_deadline = claim_deadline.unwrap_or(0);
     request.bond_lamports = 0;
-    request.finalized = false;
+    request.bond_bump = 0;
+    request.finalized_at = 0;
+    request.dest_chain_id = 0;
+    request.dest_token = [0u8; 20];
+    request.expected_dest_amount = 0;
+    request.dest_gas_fee_lamports = 0;
+    request.claim_window_secs = 0;
+    request.refund_timeout_secs = 0;
+    request.slash_bps = 0;
+    request.protocol_fee_bps = 0;
     request.bump = ctx.bumps.request_pda;
 
     Ok(())
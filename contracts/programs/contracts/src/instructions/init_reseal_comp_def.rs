@@ -1,8 +1,13 @@
-use crate::constants::CIRCUIT_URL_RESEAL;
+use crate::constants::{CIRCUIT_HASH_RESEAL, COMP_DEF_OFFSET_RESEAL};
+use crate::errors::ErrorCode;
+use crate::state::{BridgeConfig, CircuitRegistry, CircuitVersion, CIRCUIT_RESEAL};
 use crate::{ID, ID_CONST};
 use anchor_lang::prelude::*;
 use arcium_anchor::prelude::*;
 use arcium_client::idl::arcium::types::{CircuitSource, OffChainCircuitSource};
+use core::mem::size_of;
+
+use super::register_circuit_version::{activate, record_version};
 
 #[init_computation_definition_accounts("reseal_destination", payer)]
 #[derive(Accounts)]
@@ -10,6 +15,31 @@ pub struct InitResealCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + size_of::<CircuitRegistry>(),
+        seeds = [b"circuit", &[CIRCUIT_RESEAL]],
+        bump
+    )]
+    pub circuit_registry: Box<Account<'info, CircuitRegistry>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + size_of::<CircuitVersion>(),
+        seeds = [b"circuit_version", &[CIRCUIT_RESEAL], &1u32.to_le_bytes()],
+        bump
+    )]
+    pub circuit_version: Box<Account<'info, CircuitVersion>>,
+
     // sin Box: el macro espera Account<'info, MXEAccount>
     #[account(mut, address = derive_mxe_pda!())]
     pub mxe_account: Account<'info, MXEAccount>,
@@ -23,16 +53,34 @@ pub struct InitResealCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitResealCompDef>) -> Result<()> {
+/// `source` must serve the exact `build/reseal_destination.arcis` pinned in `circuit_hashes.rs`.
+pub fn handler(ctx: Context<InitResealCompDef>, source: String) -> Result<()> {
+    require!(
+        CIRCUIT_HASH_RESEAL != [0u8; 32],
+        ErrorCode::CircuitHashNotPinned
+    );
     init_comp_def(
         ctx.accounts,
         true, // público
-        0,    // versión
+        0,    // cu_amount: Arcium default
         Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: CIRCUIT_URL_RESEAL.to_string(),
-            hash: [0u8; 32], // si aún no fijas hash
+            source,
+            hash: CIRCUIT_HASH_RESEAL,
         })),
         None,
     )?;
+
+    let registry = &mut ctx.accounts.circuit_registry;
+    registry.circuit = CIRCUIT_RESEAL;
+    registry.bump = ctx.bumps.circuit_registry;
+    record_version(
+        registry,
+        &mut ctx.accounts.circuit_version,
+        ctx.bumps.circuit_version,
+        1,
+        COMP_DEF_OFFSET_RESEAL,
+        CIRCUIT_HASH_RESEAL,
+    )?;
+    activate(registry, &ctx.accounts.circuit_version);
     Ok(())
 }
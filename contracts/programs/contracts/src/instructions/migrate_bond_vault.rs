@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::BondVaultMigrated;
+use crate::instructions::claim_bridge::{take_bond, BOND_VAULT_SEED};
+use crate::state::{BridgeRequest, RequestStatus};
+
+/// Moves the bond of a claim made before vaults were keyed by request PDA out of the
+/// legacy `[BOND_VAULT_SEED, request_id]` vault (shared by every payer using that id)
+/// into the request's own vault. Permissionless; the caller pays the new vault's rent.
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct MigrateBondVault<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"request", request_owner.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    /// Only for seeds
+    /// CHECK: seeds-only
+    pub request_owner: UncheckedAccount<'info>,
+
+    /// Legacy vault keyed by request_id only
+    /// CHECK: program-owned, no data; validated by seeds
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, &request_id.to_le_bytes()],
+        bump
+    )]
+    pub legacy_bond_vault: UncheckedAccount<'info>,
+
+    /// Per-request vault
+    /// CHECK: program-owned, no data (space = 0)
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = 0,
+        seeds = [BOND_VAULT_SEED, request_pda.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateBondVault>, request_id: u64) -> Result<()> {
+    let req = &mut ctx.accounts.request_pda;
+
+    // --- Guards ---
+    require!(req.status == RequestStatus::Claimed, ErrorCode::NoClaim);
+    require!(req.bond_bump == 0, ErrorCode::BondVaultAlreadyMigrated);
+
+    let bond = req.bond_lamports;
+
+    // --- Move only this request's bond; the rest (and the rent) stays for the other payers ---
+    if bond > 0 {
+        take_bond(
+            &ctx.accounts.legacy_bond_vault.to_account_info(),
+            bond,
+            Rent::get()?.minimum_balance(0),
+            ErrorCode::LegacyBondVaultUnderfunded,
+        )?;
+        **ctx
+            .accounts
+            .bond_vault
+            .to_account_info()
+            .try_borrow_mut_lamports()? += bond;
+    }
+
+    req.bond_bump = ctx.bumps.bond_vault;
+    if req.bond_rent_payer == Pubkey::default() {
+        req.bond_rent_payer = ctx.accounts.caller.key();
+    }
+
+    emit!(BondVaultMigrated {
+        request_id,
+        solver: req.solver,
+        bond,
+    });
+
+    Ok(())
+}
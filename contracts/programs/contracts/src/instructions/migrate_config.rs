@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ErrorCode;
+use crate::events::ConfigMigrated;
+use crate::state::{BridgeConfig, BridgeConfigV0, CONFIG_VERSION};
+use crate::validation::validate_config;
+
+/// Rewrites a V0 (pre-versioning) config in the current layout. Only the V0 owner can call
+/// it; the timeouts that did not exist in V0 are supplied here.
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: old layout, decoded by hand; seeds, owner and discriminator are checked
+    #[account(mut, seeds = [b"config"], bump, owner = crate::ID)]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Tops up rent from `payer` and grows `account` to `new_len` (new bytes zeroed).
+pub(crate) fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(new_len);
+    let top_up = rent.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    account.realloc(new_len, true)?;
+    Ok(())
+}
+
+/// Checks the discriminator and that the account still has the V0 size.
+pub(crate) fn check_v0_layout(
+    data: &[u8],
+    discriminator: &[u8],
+    v0_len: usize,
+    len: usize,
+) -> Result<()> {
+    require!(
+        data.len() >= 8 && &data[..8] == discriminator,
+        ErrorCode::UnknownAccountLayout
+    );
+    require!(data.len() != len, ErrorCode::AccountAlreadyMigrated);
+    require!(data.len() == v0_len, ErrorCode::UnknownAccountLayout);
+    Ok(())
+}
+
+pub fn handler(
+    ctx: Context<MigrateConfig>,
+    refund_timeout_secs: i64,
+    close_grace_secs: i64,
+    config_timelock_secs: i64,
+) -> Result<()> {
+    let info = ctx.accounts.config.to_account_info();
+
+    let v0 = {
+        let data = info.try_borrow_data()?;
+        check_v0_layout(
+            &data,
+            BridgeConfig::DISCRIMINATOR,
+            BridgeConfigV0::LEN,
+            BridgeConfig::LEN,
+        )?;
+        BridgeConfigV0::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(ctx.accounts.authority.key(), v0.owner, ErrorCode::OnlyOwner);
+
+    let cfg = v0.upgrade(refund_timeout_secs, close_grace_secs, config_timelock_secs);
+    validate_config(&cfg)?;
+
+    grow_account(
+        &info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        BridgeConfig::LEN,
+    )?;
+    {
+        let mut data = info.try_borrow_mut_data()?;
+        let mut dst: &mut [u8] = &mut data;
+        cfg.try_serialize(&mut dst)?;
+    }
+
+    emit!(ConfigMigrated {
+        from_version: 0,
+        to_version: CONFIG_VERSION,
+    });
+    Ok(())
+}
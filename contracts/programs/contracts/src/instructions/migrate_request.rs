@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::RequestMigrated;
+use crate::instructions::migrate_config::{check_v0_layout, grow_account};
+use crate::state::{
+    BridgeConfig, BridgeRequest, BridgeRequestV0, RequestStatus, TokenConfig, TokenRoute,
+    REQUEST_VERSION,
+};
+
+/// Rewrites a V0 (pre-versioning) request in the current layout. The caller pays the extra
+/// rent (it goes back to the payer when the request is closed).
+/// The config must already be migrated: its claim window, slash and refund terms are
+/// snapshotted.
+/// V0 requests recorded no destination. A settled one is migrated as is, by anyone. An open
+/// one gets its destination from `token_route` (the chain the payer bridged to), so claims
+/// and settlement evidence are checked like any other request's; only the payer may pick
+/// that route. A claimed one is refused until its claim is released: its solver committed
+/// to a destination the request never recorded.
+#[derive(Accounts)]
+pub struct MigrateRequest<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: old layout, decoded by hand; owner, discriminator and PDA address are checked
+    #[account(mut, owner = crate::ID)]
+    pub request_pda: UncheckedAccount<'info>,
+
+    /// Open requests only: destination route of the request's mint
+    #[account(constraint = token_route.enabled @ ErrorCode::TokenRouteDisabled)]
+    pub token_route: Option<Box<Account<'info, TokenRoute>>>,
+
+    /// Open requests only: TokenConfig of the request's mint (decimals)
+    pub token_config: Option<Box<Account<'info, TokenConfig>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateRequest>) -> Result<()> {
+    let info = ctx.accounts.request_pda.to_account_info();
+
+    let v0 = {
+        let data = info.try_borrow_data()?;
+        check_v0_layout(
+            &data,
+            BridgeRequest::DISCRIMINATOR,
+            BridgeRequestV0::LEN,
+            BridgeRequest::LEN,
+        )?;
+        BridgeRequestV0::deserialize(&mut &data[8..])?
+    };
+
+    // Same seeds as at creation
+    let expected = Pubkey::create_program_address(
+        &[
+            b"request",
+            v0.payer.as_ref(),
+            &v0.request_id.to_le_bytes(),
+            &[v0.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::UnknownAccountLayout)?;
+    require_keys_eq!(info.key(), expected, ErrorCode::UnknownAccountLayout);
+
+    let cfg = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    let mut req = v0.upgrade(
+        cfg.claim_window_secs,
+        cfg.slash_bps,
+        cfg.refund_timeout_secs,
+        now,
+    );
+
+    require!(
+        req.status != RequestStatus::Claimed,
+        ErrorCode::LegacyClaimNotReleased
+    );
+    if req.status != RequestStatus::Settled {
+        require_keys_eq!(ctx.accounts.caller.key(), req.payer, ErrorCode::OnlyPayer);
+        let (Some(route), Some(token_config)) =
+            (&ctx.accounts.token_route, &ctx.accounts.token_config)
+        else {
+            return err!(ErrorCode::LegacyRouteRequired);
+        };
+        require_keys_eq!(route.mint, req.token_mint, ErrorCode::InvalidMint);
+        require_keys_eq!(token_config.mint, req.token_mint, ErrorCode::InvalidMint);
+
+        req.dest_chain_id = route.dest_chain_id;
+        req.dest_token = route.dest_token;
+        req.expected_dest_amount =
+            route.to_dest_amount(req.amount_locked, token_config.decimals)?;
+    }
+
+    grow_account(
+        &info,
+        &ctx.accounts.caller.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        BridgeRequest::LEN,
+    )?;
+    {
+        let mut data = info.try_borrow_mut_data()?;
+        let mut dst: &mut [u8] = &mut data;
+        req.try_serialize(&mut dst)?;
+    }
+
+    emit!(RequestMigrated {
+        request_id: req.request_id,
+        payer: req.payer,
+        from_version: 0,
+        to_version: REQUEST_VERSION,
+    });
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use crate::errors::ErrorCode;
+use crate::events::RequestEscrowMigrated;
+use crate::state::BridgeRequest;
+use crate::{SignerAccount, ID_CONST};
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self as token, Mint, Token, TokenAccount, TransferChecked};
+use arcium_anchor::prelude::*;
+
+/// Moves the funds of a migrated V0 request (`amount_locked + fee_locked`) out of the
+/// shared sign-PDA escrow every V0 deposit went to, into the request's own escrow ATA.
+/// Until then the request can be neither refunded nor settled. Permissionless; the caller
+/// pays the ATA rent (it goes back to the payer when the request is closed).
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct MigrateRequestEscrow<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"request", request_owner.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump,
+        constraint = request_pda.legacy_escrow @ ErrorCode::EscrowAlreadyMigrated
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    /// CHECK: seeds-only
+    pub request_owner: UncheckedAccount<'info>,
+
+    #[account(address = request_pda.token_mint @ ErrorCode::InvalidMint)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// Shared V0 escrow (owner = sign PDA)
+    #[account(
+        mut,
+        constraint = legacy_escrow.mint == mint.key() @ ErrorCode::InvalidMint,
+        constraint = legacy_escrow.owner == derive_sign_pda!() @ ErrorCode::InvalidOwner
+    )]
+    pub legacy_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+
+    /// Escrow of this request (ATA owned by the request PDA)
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = request_pda,
+    )]
+    pub escrow_token: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateRequestEscrow>, request_id: u64) -> Result<()> {
+    let req = &mut ctx.accounts.request_pda;
+    let amount = req
+        .amount_locked
+        .checked_add(req.fee_locked)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        ctx.accounts.legacy_escrow.amount >= amount,
+        ErrorCode::InsufficientEscrowBalance
+    );
+
+    let bump = ctx.bumps.sign_pda_account;
+    let signer_seeds: &[&[u8]] = &[&SIGN_PDA_SEED, &[bump]];
+    token::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.legacy_escrow.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.escrow_token.to_account_info(),
+                authority: ctx.accounts.sign_pda_account.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    req.legacy_escrow = false;
+
+    emit!(RequestEscrowMigrated {
+        request_id,
+        payer: req.payer,
+        token_mint: req.token_mint,
+        amount,
+    });
+    Ok(())
+}
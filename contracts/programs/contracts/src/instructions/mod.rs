@@ -1,29 +1,115 @@
+pub mod admin_accept;
+pub mod admin_propose;
+pub mod attest_destination;
+pub mod attester_add;
+pub mod attester_remove;
 pub mod callback;
+pub mod cancel_request;
+pub mod cancel_request_sol;
+pub mod chain_config_init;
+pub mod chain_config_set;
 pub mod claim_bridge;
+pub mod close_request;
+pub mod collect_fees;
+pub mod config_cancel;
+pub mod config_execute;
 pub mod config_init;
+pub mod config_propose;
 pub mod config_set;
 pub mod deposit;
 pub mod deposit_sol;
+pub mod freeze_request;
 pub mod init;
 pub mod init_request;
 pub mod init_reseal_comp_def;
-pub mod queue;
+pub mod migrate_bond_vault;
+pub mod migrate_config;
+pub mod migrate_request;
+pub mod migrate_request_escrow;
+pub mod pause_set;
+pub mod refund;
+pub mod refund_sol;
+pub mod register_circuit_version;
+pub mod relayer_add;
+pub mod relayer_remove;
+pub mod relayer_threshold_set;
 pub mod release_expired_claim;
+pub mod release_failed_claim;
 pub mod release_sol;
 pub mod release_spl;
+pub mod requeue_plan_payout;
+pub mod requeue_reseal;
+pub mod reseal_callback;
+pub mod roles_set;
+pub mod set_active_circuit_version;
+pub mod solver_authorize;
+pub mod solver_revoke;
+pub mod token_config_init;
+pub mod token_config_set;
+pub mod token_route_init;
+pub mod token_route_set;
 pub mod verify_and_settle;
+pub mod verify_and_settle_sol;
+pub mod vote_delivery;
+pub mod vote_settlement;
 
+pub use admin_accept::{handler as accept_admin_handler, AcceptAdmin};
+pub use admin_propose::{handler as propose_admin_handler, ProposeAdmin};
+pub use attest_destination::{handler as attest_destination_handler, AttestDestination};
+pub use attester_add::{handler as add_attester_handler, AddAttester};
+pub use attester_remove::{handler as remove_attester_handler, RemoveAttester};
 pub use callback::{handler as plan_payout_callback_handler, PlanPayoutCallback};
+pub use cancel_request::{handler as cancel_request_handler, CancelRequest};
+pub use cancel_request_sol::{handler as cancel_request_sol_handler, CancelRequestSol};
+pub use chain_config_init::{handler as init_chain_config_handler, InitChainConfig};
+pub use chain_config_set::{handler as set_chain_config_handler, SetChainConfig};
 pub use claim_bridge::{handler as claim_request_handler, ClaimRequest};
+pub use close_request::{handler as close_request_handler, CloseRequest};
+pub use collect_fees::{handler as collect_fees_handler, CollectFees};
+pub use config_cancel::{handler as cancel_config_handler, CancelConfig};
+pub use config_execute::{handler as execute_config_handler, ExecuteConfig};
 pub use config_init::{handler as init_config_handler, InitConfig};
+pub use config_propose::{handler as propose_config_handler, ProposeConfig};
 pub use config_set::{handler as set_config_handler, SetConfig};
 pub use deposit::{handler as deposit_and_queue_handler, DepositAndQueue};
 pub use deposit_sol::{handler as deposit_sol_and_queue_handler, DepositSolAndQueue};
+pub use freeze_request::{handler as freeze_request_handler, FreezeRequest};
 pub use init::{handler as init_plan_payout_comp_def_handler, InitPlanPayoutCompDef};
 pub use init_request::{handler as init_request_handler, InitRequest};
 pub use init_reseal_comp_def::{handler as init_reseal_comp_def_handler, InitResealCompDef};
-pub use queue::{handler as queue_plan_payout_handler, QueuePlanPayout};
+pub use migrate_bond_vault::{handler as migrate_bond_vault_handler, MigrateBondVault};
+pub use migrate_config::{handler as migrate_config_handler, MigrateConfig};
+pub use migrate_request::{handler as migrate_request_handler, MigrateRequest};
+pub use migrate_request_escrow::{handler as migrate_request_escrow_handler, MigrateRequestEscrow};
+pub use pause_set::{handler as set_pause_handler, SetPause};
+pub use refund::{handler as refund_request_handler, RefundRequest};
+pub use refund_sol::{handler as refund_request_sol_handler, RefundRequestSol};
+pub use register_circuit_version::{
+    handler as register_circuit_version_handler, RegisterCircuitVersion,
+};
+pub use relayer_add::{handler as add_relayer_handler, AddRelayer};
+pub use relayer_remove::{handler as remove_relayer_handler, RemoveRelayer};
+pub use relayer_threshold_set::{handler as set_relayer_threshold_handler, SetRelayerThreshold};
 pub use release_expired_claim::{handler as release_expired_claim_handler, ReleaseExpiredClaim};
+pub use release_failed_claim::{handler as release_failed_claim_handler, ReleaseFailedClaim};
 pub use release_sol::{handler as release_sol_handler, ReleaseSol};
 pub use release_spl::{handler as release_spl_handler, ReleaseSpl};
+pub use requeue_plan_payout::{handler as requeue_plan_payout_handler, RequeuePlanPayout};
+pub use requeue_reseal::{handler as requeue_reseal_handler, RequeueReseal};
+pub use reseal_callback::{
+    handler as reseal_destination_callback_handler, ResealDestinationCallback,
+};
+pub use roles_set::{handler as set_roles_handler, SetRoles};
+pub use set_active_circuit_version::{
+    handler as set_active_circuit_version_handler, SetActiveCircuitVersion,
+};
+pub use solver_authorize::{handler as authorize_solver_handler, AuthorizeSolver};
+pub use solver_revoke::{handler as revoke_solver_handler, RevokeSolver};
+pub use token_config_init::{handler as init_token_config_handler, InitTokenConfig};
+pub use token_config_set::{handler as set_token_config_handler, SetTokenConfig};
+pub use token_route_init::{handler as init_token_route_handler, InitTokenRoute};
+pub use token_route_set::{handler as set_token_route_handler, SetTokenRoute};
 pub use verify_and_settle::{handler as verify_and_settle_spl_handler, VerifyAndSettleSpl};
+pub use verify_and_settle_sol::{handler as verify_and_settle_sol_handler, VerifyAndSettleSol};
+pub use vote_delivery::{handler as vote_delivery_handler, VoteDelivery};
+pub use vote_settlement::{handler as vote_settlement_handler, VoteSettlement};
@@ -0,0 +1,51 @@
+use crate::errors::ErrorCode;
+use crate::events::{Paused, Unpaused};
+use crate::state::{BridgeConfig, PAUSE_ALL};
+use anchor_lang::prelude::*;
+
+/// Replaces the pause bitmask (`PAUSE_*`). The pauser may only add flags; clearing any
+/// flag takes the admin.
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = authority.key() == config.pauser
+            || authority.key() == config.admin @ ErrorCode::OnlyPauser
+    )]
+    pub config: Account<'info, BridgeConfig>,
+}
+
+pub fn handler(ctx: Context<SetPause>, paused: u8) -> Result<()> {
+    require!(paused & !PAUSE_ALL == 0, ErrorCode::InvalidPauseFlags);
+
+    let cfg = &mut ctx.accounts.config;
+    let by = ctx.accounts.authority.key();
+    require!(
+        paused & cfg.paused == cfg.paused || by == cfg.admin,
+        ErrorCode::OnlyOwner
+    );
+
+    let newly_paused = paused & !cfg.paused;
+    let newly_unpaused = cfg.paused & !paused;
+    cfg.paused = paused;
+
+    if newly_paused != 0 {
+        emit!(Paused {
+            flags: newly_paused,
+            paused,
+            by,
+        });
+    }
+    if newly_unpaused != 0 {
+        emit!(Unpaused {
+            flags: newly_unpaused,
+            paused,
+            by,
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use crate::errors::ErrorCode;
+use crate::events::BridgeRefunded;
+use crate::state::{BridgeConfig, BridgeRequest, RequestStatus};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self as token, CloseAccount, Mint, Token, TokenAccount, TransferChecked};
+
+/// Refund of an unclaimed request back to its payer.
+/// Only the original payer can call it, once the request's `refund_timeout_secs` (snapshotted
+/// at deposit) have passed since `created_at`.
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct RefundRequest<'info> {
+    /// Original depositor (part of the request seeds)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Global config (compute failure limit)
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"request", payer.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump,
+        constraint = request_pda.token_mint == mint.key() @ ErrorCode::InvalidMint,
+        constraint = !request_pda.legacy_escrow @ ErrorCode::LegacyEscrow
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// Escrow of this request (ATA owned by the request PDA); closed after the refund
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = request_pda,
+    )]
+    pub escrow_token: Box<Account<'info, TokenAccount>>,
+
+    /// Payer's token account receiving the refund
+    #[account(
+        mut,
+        constraint = payer_token.mint == mint.key() @ ErrorCode::InvalidMint,
+        constraint = payer_token.owner == payer.key() @ ErrorCode::InvalidOwner
+    )]
+    pub payer_token: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Shared guards for both refund paths. Returns the full amount to give back.
+/// The status is checked by the `Refunded` transition: a claimed request, even with an
+/// expired claim, must go through `release_expired_claim` first (bond accounting), and a
+/// claim frozen with its bond can only be cancelled by the admin.
+/// After `max_compute_failures` aborted computations the timeout no longer applies.
+pub(crate) fn refundable_amount(cfg: &BridgeConfig, req: &BridgeRequest, now: i64) -> Result<u64> {
+    require!(req.bond_lamports == 0, ErrorCode::ActiveClaim);
+    let refundable_at = req
+        .created_at
+        .checked_add(req.refund_timeout_secs)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let gave_up = cfg.max_compute_failures > 0 && req.compute_failures >= cfg.max_compute_failures;
+    require!(
+        gave_up || now >= refundable_at,
+        ErrorCode::RefundTimeoutNotReached
+    );
+
+    Ok(req
+        .amount_locked
+        .checked_add(req.fee_locked)
+        .ok_or(ErrorCode::MathOverflow)?)
+}
+
+/// Sends `amount` from the request's escrow to `payer_token`, then closes the emptied escrow
+/// and hands back the parked destination gas fee, both to `payer`. Shared with the cancel.
+pub(crate) fn return_escrow<'info>(
+    req: &mut Account<'info, BridgeRequest>,
+    payer: &AccountInfo<'info>,
+    escrow_token: &mut Account<'info, TokenAccount>,
+    mint: &Account<'info, Mint>,
+    payer_token: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        escrow_token.amount >= amount,
+        ErrorCode::InsufficientEscrowBalance
+    );
+
+    // --- SPL transfer (escrow -> payer) signed by the request PDA ---
+    let payer_key = payer.key();
+    let id_bytes = req.request_id.to_le_bytes();
+    let bump = [req.bump];
+    let signer_seeds: &[&[u8]] = &[b"request", payer_key.as_ref(), &id_bytes, &bump];
+
+    token::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: escrow_token.to_account_info(),
+                mint: mint.to_account_info(),
+                to: payer_token.clone(),
+                authority: req.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    // --- Close the (now empty) escrow: its rent goes back to the payer ---
+    escrow_token.reload()?;
+    if escrow_token.amount == 0 {
+        token::close_account(CpiContext::new_with_signer(
+            token_program.clone(),
+            CloseAccount {
+                account: escrow_token.to_account_info(),
+                destination: payer.clone(),
+                authority: req.to_account_info(),
+            },
+            &[signer_seeds],
+        ))?;
+    }
+
+    return_gas_fee(req, payer)
+}
+
+/// Destination gas fee parked on the request PDA goes back to the payer.
+pub(crate) fn return_gas_fee<'info>(
+    req: &mut Account<'info, BridgeRequest>,
+    payer: &AccountInfo<'info>,
+) -> Result<()> {
+    let gas_fee = req.dest_gas_fee_lamports;
+    if gas_fee > 0 {
+        **req.to_account_info().try_borrow_mut_lamports()? -= gas_fee;
+        **payer.try_borrow_mut_lamports()? += gas_fee;
+        req.dest_gas_fee_lamports = 0;
+    }
+    Ok(())
+}
+
+pub fn handler(ctx: Context<RefundRequest>, request_id: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let req = &mut ctx.accounts.request_pda;
+    let amount = refundable_amount(&ctx.accounts.config, req, now)?;
+    req.transition(RequestStatus::Refunded)?;
+    req.finalized_at = now;
+
+    return_escrow(
+        req,
+        &ctx.accounts.payer.to_account_info(),
+        &mut ctx.accounts.escrow_token,
+        &ctx.accounts.mint,
+        &ctx.accounts.payer_token.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        amount,
+    )?;
+
+    emit!(BridgeRefunded {
+        request_id,
+        payer: ctx.accounts.payer.key(),
+        token_mint: ctx.accounts.mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
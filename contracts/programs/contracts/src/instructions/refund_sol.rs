@@ -0,0 +1,98 @@
+use crate::errors::ErrorCode;
+use crate::events::BridgeRefunded;
+use crate::instructions::refund::{refundable_amount, return_gas_fee};
+use crate::state::{BridgeConfig, BridgeRequest, RequestStatus};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self as token, spl_token, CloseAccount, Token, TokenAccount};
+
+/// Refund of an unclaimed SOL request. The request's own WSOL escrow only holds this
+/// request's lamports, so closing it to the payer unwraps the refund (plus the escrow rent).
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct RefundRequestSol<'info> {
+    /// Original depositor (part of the request seeds); receives native SOL
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Global config (compute failure limit)
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"request", payer.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump,
+        constraint = request_pda.token_mint == spl_token::native_mint::id() @ ErrorCode::InvalidMint,
+        constraint = !request_pda.legacy_escrow @ ErrorCode::LegacyEscrow
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    /// WSOL escrow of this request (ATA owned by the request PDA)
+    #[account(
+        mut,
+        associated_token::mint = spl_token::native_mint::id(),
+        associated_token::authority = request_pda,
+    )]
+    pub escrow_wsol: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Closes the request's WSOL escrow to `payer` (unwrapping the lamports) and hands back the
+/// parked destination gas fee. Shared with the cancel.
+pub(crate) fn return_escrow_sol<'info>(
+    req: &mut Account<'info, BridgeRequest>,
+    payer: &AccountInfo<'info>,
+    escrow_wsol: &Account<'info, TokenAccount>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        escrow_wsol.amount >= amount,
+        ErrorCode::InsufficientEscrowBalance
+    );
+
+    // --- Close the escrow: the payer gets the lamports back as native SOL ---
+    let payer_key = payer.key();
+    let id_bytes = req.request_id.to_le_bytes();
+    let bump = [req.bump];
+    let signer_seeds: &[&[u8]] = &[b"request", payer_key.as_ref(), &id_bytes, &bump];
+
+    token::close_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        CloseAccount {
+            account: escrow_wsol.to_account_info(),
+            destination: payer.clone(),
+            authority: req.to_account_info(),
+        },
+        &[signer_seeds],
+    ))?;
+
+    return_gas_fee(req, payer)
+}
+
+pub fn handler(ctx: Context<RefundRequestSol>, request_id: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let req = &mut ctx.accounts.request_pda;
+    let amount = refundable_amount(&ctx.accounts.config, req, now)?;
+    req.transition(RequestStatus::Refunded)?;
+    req.finalized_at = now;
+
+    return_escrow_sol(
+        req,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.escrow_wsol,
+        &ctx.accounts.token_program.to_account_info(),
+        amount,
+    )?;
+
+    emit!(BridgeRefunded {
+        request_id,
+        payer: ctx.accounts.payer.key(),
+        token_mint: spl_token::native_mint::id(),
+        amount,
+    });
+
+    Ok(())
+}
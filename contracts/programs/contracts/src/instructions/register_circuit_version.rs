@@ -0,0 +1,127 @@
+use crate::errors::ErrorCode;
+use crate::events::{CircuitVersionActivated, CircuitVersionRegistered};
+use crate::state::{version_comp_def_offset, BridgeConfig, CircuitRegistry, CircuitVersion};
+use crate::ID_CONST;
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CircuitSource;
+use core::mem::size_of;
+
+/// Records a new version of a circuit. It is not used until `set_active_circuit_version`
+/// switches to it. The registry and version 1 come from the circuit's comp-def init; the
+/// new comp-def (`<circuit>_v<version>`, see `version_comp_def_offset`) must already be
+/// initialized in Arcium with an off-chain source whose hash is `hash`.
+#[derive(Accounts)]
+#[instruction(circuit: u8, version: u32)]
+pub struct RegisterCircuitVersion<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"circuit", &[circuit]],
+        bump = circuit_registry.bump
+    )]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + size_of::<CircuitVersion>(),
+        seeds = [b"circuit_version", &[circuit], &version.to_le_bytes()],
+        bump
+    )]
+    pub circuit_version: Account<'info, CircuitVersion>,
+
+    /// Comp-def of this version; checked against the circuit and `hash` in the handler
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterCircuitVersion>,
+    circuit: u8,
+    version: u32,
+    hash: [u8; 32],
+) -> Result<()> {
+    // Arcium derives the offset from the circuit name, so only this circuit's comp-def fits
+    let comp_def_offset =
+        version_comp_def_offset(circuit, version).ok_or(ErrorCode::InvalidCircuitVersion)?;
+    require_keys_eq!(
+        ctx.accounts.comp_def_account.key(),
+        derive_comp_def_pda!(comp_def_offset),
+        ErrorCode::CompDefCircuitMismatch
+    );
+
+    require!(hash != [0u8; 32], ErrorCode::CircuitHashNotPinned);
+    require!(
+        source_hash(&ctx.accounts.comp_def_account) == Some(hash),
+        ErrorCode::CircuitHashMismatch
+    );
+
+    record_version(
+        &mut ctx.accounts.circuit_registry,
+        &mut ctx.accounts.circuit_version,
+        ctx.bumps.circuit_version,
+        version,
+        comp_def_offset,
+        hash,
+    )
+}
+
+/// Hash the MXE verifies the fetched circuit against; only off-chain sources carry one.
+fn source_hash(comp_def: &ComputationDefinitionAccount) -> Option<[u8; 32]> {
+    match &comp_def.circuit_source {
+        CircuitSource::OffChain(source) => Some(source.hash),
+        _ => None,
+    }
+}
+
+pub(crate) fn record_version(
+    registry: &mut CircuitRegistry,
+    entry: &mut CircuitVersion,
+    bump: u8,
+    version: u32,
+    comp_def_offset: u32,
+    hash: [u8; 32],
+) -> Result<()> {
+    require!(
+        registry.latest_version.checked_add(1) == Some(version),
+        ErrorCode::InvalidCircuitVersion
+    );
+    registry.latest_version = version;
+
+    entry.circuit = registry.circuit;
+    entry.version = version;
+    entry.comp_def_offset = comp_def_offset;
+    entry.hash = hash;
+    entry.registered_at = Clock::get()?.unix_timestamp;
+    entry.bump = bump;
+
+    emit!(CircuitVersionRegistered {
+        circuit: entry.circuit,
+        version,
+        comp_def_offset,
+        hash,
+    });
+    Ok(())
+}
+
+pub(crate) fn activate(registry: &mut CircuitRegistry, entry: &CircuitVersion) {
+    registry.active_version = entry.version;
+    registry.active_offset = entry.comp_def_offset;
+
+    emit!(CircuitVersionActivated {
+        circuit: entry.circuit,
+        version: entry.version,
+        comp_def_offset: entry.comp_def_offset,
+    });
+}
@@ -0,0 +1,31 @@
+use crate::errors::ErrorCode;
+use crate::events::RelayerAdded;
+use crate::state::{BridgeConfig, MAX_RELAYERS};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddRelayer<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+}
+
+pub fn handler(ctx: Context<AddRelayer>, relayer: Pubkey) -> Result<()> {
+    let cfg = &mut ctx.accounts.config;
+
+    require!(!cfg.is_relayer(&relayer), ErrorCode::RelayerAlreadyExists);
+    let count = cfg.relayer_count as usize;
+    require!(count < MAX_RELAYERS, ErrorCode::TooManyRelayers);
+
+    cfg.relayers[count] = relayer;
+    cfg.relayer_count += 1;
+
+    emit!(RelayerAdded { relayer });
+    Ok(())
+}
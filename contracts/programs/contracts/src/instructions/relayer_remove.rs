@@ -0,0 +1,40 @@
+use crate::errors::ErrorCode;
+use crate::events::RelayerRemoved;
+use crate::state::BridgeConfig;
+use crate::validation::check_relayer_threshold;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveRelayer<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+}
+
+pub fn handler(ctx: Context<RemoveRelayer>, relayer: Pubkey) -> Result<()> {
+    let cfg = &mut ctx.accounts.config;
+
+    let idx = cfg
+        .active_relayers()
+        .iter()
+        .position(|k| *k == relayer)
+        .ok_or(ErrorCode::RelayerNotFound)?;
+
+    // Swap-remove: order of the set is not meaningful
+    let last = cfg.relayer_count as usize - 1;
+    cfg.relayers[idx] = cfg.relayers[last];
+    cfg.relayers[last] = Pubkey::default();
+    cfg.relayer_count -= 1;
+
+    // The remaining set must still be able to authorize settlements and deliveries
+    check_relayer_threshold(cfg.quorum(), cfg.relayer_count)?;
+
+    emit!(RelayerRemoved { relayer });
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use crate::errors::ErrorCode;
+use crate::events::RelayerThresholdSet;
+use crate::state::BridgeConfig;
+use crate::validation::check_relayer_threshold;
+use anchor_lang::prelude::*;
+
+/// Rounds already open keep the threshold they started with.
+#[derive(Accounts)]
+pub struct SetRelayerThreshold<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+}
+
+pub fn handler(ctx: Context<SetRelayerThreshold>, threshold: u8) -> Result<()> {
+    let cfg = &mut ctx.accounts.config;
+    check_relayer_threshold(threshold, cfg.relayer_count)?;
+    cfg.relayer_threshold = threshold;
+
+    emit!(RelayerThresholdSet { threshold });
+    Ok(())
+}
@@ -3,9 +3,9 @@ use anchor_lang::prelude::*;
 
 use crate::errors::ErrorCode;
 use crate::events::BridgeClaimExpired;
-use crate::state::{BridgeConfig, BridgeRequest};
+use crate::state::{BridgeConfig, BridgeRequest, RequestStatus};
 // Usa el MISMO seed que en claim_request.rs (evita duplicar la constante)
-use crate::instructions::claim_bridge::BOND_VAULT_SEED;
+use crate::instructions::claim_bridge::{split_bond, take_bond, BOND_VAULT_SEED};
 
 #[derive(Accounts)]
 #[instruction(request_id: u64)]
@@ -21,7 +21,8 @@ pub struct ReleaseExpiredClaim<'info> {
     #[account(
         mut,
         seeds = [b"request", request_owner.key().as_ref(), &request_id.to_le_bytes()],
-        bump = request_pda.bump
+        bump = request_pda.bump,
+        constraint = request_pda.bond_bump != 0 @ ErrorCode::LegacyBondVault
     )]
     pub request_pda: Box<Account<'info, BridgeRequest>>,
 
@@ -30,30 +31,30 @@ pub struct ReleaseExpiredClaim<'info> {
     pub request_owner: UncheckedAccount<'info>,
 
     /// PDA que mantiene el bond del solver (lamports)
+    /// CHECK: program-owned, no data; validated by seeds
     #[account(
         mut,
-        seeds = [BOND_VAULT_SEED, &request_id.to_le_bytes()],
-        bump
+        seeds = [BOND_VAULT_SEED, request_pda.key().as_ref()],
+        bump = request_pda.bond_bump
     )]
-    pub bond_vault: SystemAccount<'info>,
+    pub bond_vault: UncheckedAccount<'info>,
 
     /// Reembolso va al *solver anterior*. Validado contra request_pda.solver.
     /// CHECK: validado en runtime con require_keys_eq!
     #[account(mut)]
     pub prev_solver: UncheckedAccount<'info>,
 
-    /// Parte del slash va al collector (para simplicidad, config.owner)
-    /// CHECK: address validada contra config.owner
-    #[account(mut, address = config.owner)]
+    /// Parte del slash va al collector
+    /// CHECK: address validada contra config.slash_collector
+    #[account(mut, address = config.slash_collector)]
     pub slash_collector: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<ReleaseExpiredClaim>, request_id: u64) -> Result<()> {
-    let cfg = &ctx.accounts.config;
     let req = &mut ctx.accounts.request_pda;
 
     // --- Guards ---
-    require!(req.claimed, ErrorCode::NoClaim);
+    require!(req.status == RequestStatus::Claimed, ErrorCode::NoClaim);
 
     let now = Clock::get()?.unix_timestamp;
     // Si aún no expiró, sigue activa -> no liberar
@@ -70,28 +71,16 @@ pub fn handler(ctx: Context<ReleaseExpiredClaim>, request_id: u64) -> Result<()>
     require!(req.bond_lamports > 0, ErrorCode::BondTooLow);
 
     // --- Cálculo de slash/refund ---
-    let bond = req.bond_lamports as u128;
-    let slash = bond
-        .checked_mul(cfg.slash_bps as u128)
-        .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(10_000)
-        .ok_or(ErrorCode::MathOverflow)?;
-    let refund = bond.checked_sub(slash).ok_or(ErrorCode::MathOverflow)?;
-
-    let slash_u64 = u64::try_from(slash).map_err(|_| ErrorCode::MathOverflow)?;
-    let refund_u64 = u64::try_from(refund).map_err(|_| ErrorCode::MathOverflow)?;
-
-    // Sanity: el vault debe tener al menos el bond
-    let vault_lamports = **ctx.accounts.bond_vault.to_account_info().lamports.borrow();
-    require!(vault_lamports >= req.bond_lamports, ErrorCode::MathOverflow);
+    let (slash_u64, refund_u64) = split_bond(req.bond_lamports, req.slash_bps)?;
 
     // --- Mover lamports (PDA -> cuentas sistema) ---
-    // Resta total del bond al vault
-    **ctx
-        .accounts
-        .bond_vault
-        .to_account_info()
-        .try_borrow_mut_lamports()? -= req.bond_lamports;
+    // Resta total del bond al vault (nunca su renta)
+    take_bond(
+        &ctx.accounts.bond_vault.to_account_info(),
+        req.bond_lamports,
+        Rent::get()?.minimum_balance(0),
+        ErrorCode::MathOverflow,
+    )?;
 
     // Agrega slash al collector
     if slash_u64 > 0 {
@@ -111,8 +100,8 @@ pub fn handler(ctx: Context<ReleaseExpiredClaim>, request_id: u64) -> Result<()>
             .try_borrow_mut_lamports()? += refund_u64;
     }
 
-    // --- Limpia el estado para permitir un nuevo claim ---
-    req.claimed = false;
+    // --- Limpia el estado para permitir un nuevo claim (sigue atestada) ---
+    req.transition(RequestStatus::Attested)?;
     req.solver = Pubkey::default();
     req.claim_deadline = 0;
     req.bond_lamports = 0;
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::BridgeClaimReleased;
+use crate::instructions::claim_bridge::{take_bond, BOND_VAULT_SEED};
+use crate::state::{BridgeRequest, ClaimReseal, RequestStatus, ResealStatus};
+
+/// Lets the solver walk away from a claim whose reseal the MPC aborted: it never got the
+/// destination, so the whole bond is returned (no slash) and the request can be claimed again.
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct ReleaseFailedClaim<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"request", request_owner.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump,
+        constraint = request_pda.bond_bump != 0 @ ErrorCode::LegacyBondVault
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    /// CHECK: seeds-only
+    pub request_owner: UncheckedAccount<'info>,
+
+    /// CHECK: program-owned, no data; validated by seeds
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, request_pda.key().as_ref()],
+        bump = request_pda.bond_bump
+    )]
+    pub bond_vault: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"reseal", request_pda.key().as_ref()],
+        bump = claim_reseal.bump,
+        constraint = claim_reseal.status == ResealStatus::Failed @ ErrorCode::ResealNotFailed
+    )]
+    pub claim_reseal: Box<Account<'info, ClaimReseal>>,
+}
+
+pub fn handler(ctx: Context<ReleaseFailedClaim>, request_id: u64) -> Result<()> {
+    let req = &mut ctx.accounts.request_pda;
+
+    require!(req.status == RequestStatus::Claimed, ErrorCode::NoClaim);
+    require_keys_eq!(
+        ctx.accounts.solver.key(),
+        req.solver,
+        ErrorCode::InvalidOwner
+    );
+    require_keys_eq!(
+        ctx.accounts.claim_reseal.solver,
+        req.solver,
+        ErrorCode::ResealNotFailed
+    );
+
+    let bond = req.bond_lamports;
+    take_bond(
+        &ctx.accounts.bond_vault.to_account_info(),
+        bond,
+        Rent::get()?.minimum_balance(0),
+        ErrorCode::MathOverflow,
+    )?;
+    **ctx
+        .accounts
+        .solver
+        .to_account_info()
+        .try_borrow_mut_lamports()? += bond;
+
+    req.transition(RequestStatus::Attested)?;
+    req.solver = Pubkey::default();
+    req.claim_deadline = 0;
+    req.bond_lamports = 0;
+
+    emit!(BridgeClaimReleased {
+        request_id,
+        solver: ctx.accounts.solver.key(),
+        bond_returned: bond,
+    });
+    Ok(())
+}
@@ -1,49 +1,85 @@
 // programs/contracts/src/instructions/release_sol.rs
+use crate::attestation::count_attestations;
+use crate::digest::delivery_digest;
 use crate::errors::ErrorCode;
 use crate::events::IncomingBridgeDelivered;
-use crate::state::BridgeConfig;
+use crate::state::{
+    require_quorum, ActionVotes, AttesterSet, BridgeConfig, DeliveryReceipt, PAUSE_DELIVERIES,
+    VOTE_KIND_DELIVERY,
+};
 use crate::{SignerAccount, ID_CONST};
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self as token, spl_token, CloseAccount, Token, TokenAccount, Transfer};
+use anchor_lang::system_program;
+use anchor_spl::token::{
+    self as token, spl_token, CloseAccount, Mint, Token, TokenAccount, Transfer,
+};
 use arcium_anchor::prelude::*;
+use core::mem::size_of;
 
-/// Libera WSOL desde el escrow (owner = PDA firmante) hacia una cuenta WSOL del receptor.
-/// Si el escrow queda en 0, opcionalmente se cierra para recuperar renta.
+/// Libera SOL nativo desde el escrow WSOL compartido (owner = PDA firmante) directo a la
+/// wallet del receptor: no necesita cuenta WSOL. El monto pasa por una cuenta WSOL temporal
+/// que se cierra al relayer (unwrap) y el relayer lo reenvía al receptor; el relayer solo
+/// recupera la renta de la temporal. El escrow compartido nunca se cierra aquí.
 #[derive(Accounts)]
+#[instruction(source_chain_id: u64, source_request_id: [u8; 32])]
 pub struct ReleaseSol<'info> {
-    /// Relayer autorizado (debe ser el owner en config)
-    #[account(mut, address = config.owner)]
+    /// Relayer autorizado (uno de config.relayers)
+    #[account(mut, constraint = config.is_relayer(&relayer.key()) @ ErrorCode::OnlyRelayer)]
     pub relayer: Signer<'info>,
 
     /// Config global (solo para autorizar al relayer)
-    #[account(seeds=[b"config"], bump = config.bump)]
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_DELIVERIES) @ ErrorCode::DeliveriesPaused
+    )]
     pub config: Account<'info, BridgeConfig>,
 
-    /// Escrow WSOL (mint = NATIVE_MINT) propiedad de la PDA firmante
+    /// Replay guard: one receipt per (source chain, source request)
     #[account(
-        mut,
-        constraint = escrow_wsol.mint == spl_token::native_mint::id() @ ErrorCode::InvalidMint,
-        constraint = escrow_wsol.owner == derive_sign_pda!() @ ErrorCode::InvalidOwner
+        init,
+        payer = relayer,
+        space = 8 + size_of::<DeliveryReceipt>(),
+        seeds = [b"delivery", &source_chain_id.to_le_bytes(), source_request_id.as_ref()],
+        bump
     )]
-    pub escrow_wsol: Box<Account<'info, TokenAccount>>, // ⬅️ Box para bajar stack
+    pub delivery_receipt: Box<Account<'info, DeliveryReceipt>>,
 
-    /// Cuenta WSOL del receptor (puede ser su ATA o cualquier TokenAccount WSOL)
+    /// Relayer votes for this delivery; may be omitted while the threshold is 1.
+    /// Closed to the executing relayer.
     #[account(
         mut,
-        constraint = recipient_wsol.mint == spl_token::native_mint::id() @ ErrorCode::InvalidMint,
-        constraint = recipient_wsol.owner == recipient.key() @ ErrorCode::InvalidOwner
+        close = relayer,
+        seeds = [b"votes", action_votes.digest.as_ref()],
+        bump = action_votes.bump
     )]
-    pub recipient_wsol: Box<Account<'info, TokenAccount>>,
+    pub action_votes: Option<Box<Account<'info, ActionVotes>>>,
 
-    /// Wallet pública del receptor (dueña de `recipient_wsol`)
-    /// CHECK: solo se usa para constraint/event
-    pub recipient: UncheckedAccount<'info>,
+    /// Off-chain attesters; with the instructions sysvar, their signed digests count as votes
+    #[account(seeds = [b"attesters"], bump = attester_set.bump)]
+    pub attester_set: Option<Box<Account<'info, AttesterSet>>>,
+
+    /// Instructions sysvar, read for the secp256k1 / ed25519 precompile instructions
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: validated by constraint
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    #[account(address = spl_token::native_mint::id() @ ErrorCode::InvalidMint)]
+    pub mint: Box<Account<'info, Mint>>,
 
-    /// Destino de renta si se cierra el escrow
-    /// CHECK: solo recibe lamports del CloseAccount
+    /// Escrow WSOL (mint = NATIVE_MINT) propiedad de la PDA firmante
+    #[account(
+        mut,
+        constraint = escrow_wsol.mint == spl_token::native_mint::id() @ ErrorCode::InvalidMint,
+        constraint = escrow_wsol.owner == derive_sign_pda!() @ ErrorCode::InvalidOwner
+    )]
+    pub escrow_wsol: Box<Account<'info, TokenAccount>>, // ⬅️ Box para bajar stack
+
+    /// Wallet del receptor; recibe lamports (puede no existir todavía)
+    /// CHECK: solo recibe lamports vía system transfer
     #[account(mut)]
-    pub escrow_refund_destination: UncheckedAccount<'info>,
+    pub recipient: UncheckedAccount<'info>,
 
     /// PDA firmante de Arcium (misma usada en depósito)
     #[account(
@@ -54,19 +90,68 @@ pub struct ReleaseSol<'info> {
     )]
     pub sign_pda_account: Account<'info, SignerAccount>,
 
+    /// WSOL temporal para el unwrap; se crea y se cierra en esta instrucción
+    #[account(
+        init,
+        payer = relayer,
+        token::mint = mint,
+        token::authority = sign_pda_account,
+        seeds = [b"unwrap", sign_pda_account.key().as_ref()],
+        bump
+    )]
+    pub unwrap_wsol: Box<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
-    // Puedes omitir `system_program` si no lo usas explícitamente
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<ReleaseSol>, amount: u64) -> Result<()> {
+pub fn handler(
+    ctx: Context<ReleaseSol>,
+    source_chain_id: u64,
+    source_request_id: [u8; 32],
+    source_tx_hash: [u8; 32],
+    amount: u64,
+) -> Result<()> {
+    let digest = delivery_digest(
+        source_chain_id,
+        &source_request_id,
+        &source_tx_hash,
+        &ctx.accounts.recipient.key(),
+        &ctx.accounts.mint.key(),
+        amount,
+    );
+    let attestations = count_attestations(
+        ctx.accounts.attester_set.as_deref().map(|s| &**s),
+        ctx.accounts.instructions_sysvar.as_deref(),
+        &digest,
+    )?;
+    require_quorum(
+        &ctx.accounts.config,
+        ctx.accounts.action_votes.as_deref().map(|v| &**v),
+        &digest,
+        VOTE_KIND_DELIVERY,
+        &ctx.accounts.relayer.key(),
+        attestations,
+        Clock::get()?.unix_timestamp,
+    )?;
+
     // 0) Chequeo defensivo: balance suficiente en escrow
     require!(
         ctx.accounts.escrow_wsol.amount >= amount,
         ErrorCode::InsufficientEscrowBalance
     );
 
-    // 1) Transferencia WSOL (escrow -> recipient_wsol) firmada por la PDA
+    // Un receptor nuevo (sin lamports) solo puede crearse con al menos la renta mínima;
+    // si no, el system transfer fallaría con un error de renta poco claro
+    let recipient = ctx.accounts.recipient.to_account_info();
+    if recipient.lamports() == 0 {
+        require!(
+            amount >= Rent::get()?.minimum_balance(0),
+            ErrorCode::DeliveryBelowRentExemption
+        );
+    }
+
+    // 1) escrow -> WSOL temporal, firmado por la PDA
     let bump = ctx.bumps.sign_pda_account;
     let signer_seeds: &[&[u8]] = &[&SIGN_PDA_SEED, &[bump]];
     token::transfer(
@@ -74,7 +159,7 @@ pub fn handler(ctx: Context<ReleaseSol>, amount: u64) -> Result<()> {
             ctx.accounts.token_program.to_account_info(),
             Transfer {
                 from: ctx.accounts.escrow_wsol.to_account_info(),
-                to: ctx.accounts.recipient_wsol.to_account_info(),
+                to: ctx.accounts.unwrap_wsol.to_account_info(),
                 authority: ctx.accounts.sign_pda_account.to_account_info(),
             },
             &[signer_seeds],
@@ -82,24 +167,48 @@ pub fn handler(ctx: Context<ReleaseSol>, amount: u64) -> Result<()> {
         amount,
     )?;
 
-    // 2) Opcional: si queda en 0, cierra la cuenta para recuperar renta
-    if ctx.accounts.escrow_wsol.amount == 0 {
-        token::close_account(CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            CloseAccount {
-                account: ctx.accounts.escrow_wsol.to_account_info(),
-                destination: ctx.accounts.escrow_refund_destination.to_account_info(),
-                authority: ctx.accounts.sign_pda_account.to_account_info(),
+    // 2) Cerrar la temporal al relayer (unwrap: monto + su renta)
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.unwrap_wsol.to_account_info(),
+            destination: ctx.accounts.relayer.to_account_info(),
+            authority: ctx.accounts.sign_pda_account.to_account_info(),
+        },
+        &[signer_seeds],
+    ))?;
+
+    // 3) Relayer -> receptor (SOL nativo)
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.relayer.to_account_info(),
+                to: recipient,
             },
-            &[signer_seeds],
-        ))?;
-    }
+        ),
+        amount,
+    )?;
+
+    // 4) Recibo (anti-replay) + evento espejo
+    let receipt = &mut ctx.accounts.delivery_receipt;
+    receipt.source_chain_id = source_chain_id;
+    receipt.source_request_id = source_request_id;
+    receipt.source_tx_hash = source_tx_hash;
+    receipt.recipient = ctx.accounts.recipient.key();
+    receipt.token = spl_token::native_mint::id();
+    receipt.amount = amount;
+    receipt.relayer = ctx.accounts.relayer.key();
+    receipt.delivered_at = Clock::get()?.unix_timestamp;
+    receipt.bump = ctx.bumps.delivery_receipt;
 
-    // 3) Evento espejo
     emit!(IncomingBridgeDelivered {
         recipient: ctx.accounts.recipient.key(),
         token: spl_token::native_mint::id(),
         amount,
+        source_chain_id,
+        source_request_id,
+        source_tx_hash,
     });
 
     Ok(())
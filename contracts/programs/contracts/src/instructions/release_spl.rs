@@ -1,17 +1,24 @@
+use crate::attestation::count_attestations;
+use crate::digest::delivery_digest;
 use crate::errors::ErrorCode;
 use crate::events::IncomingBridgeDelivered;
-use crate::state::BridgeConfig;
+use crate::state::{
+    require_quorum, ActionVotes, AttesterSet, BridgeConfig, DeliveryReceipt, PAUSE_DELIVERIES,
+    VOTE_KIND_DELIVERY,
+};
 use crate::{SignerAccount, ID_CONST}; // ⬅️ los macros de Arcium usan ID/ID_CONST
 
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self as token, Mint, Token, TokenAccount, TransferChecked};
 use arcium_anchor::prelude::*;
+use core::mem::size_of;
 
 #[derive(Accounts)]
+#[instruction(source_chain_id: u64, source_request_id: [u8; 32])]
 pub struct ReleaseSpl<'info> {
-    /// Relayer autorizado (dueño del config)
-    #[account(mut, address = config.owner)]
+    /// Relayer autorizado (uno de config.relayers)
+    #[account(mut, constraint = config.is_relayer(&relayer.key()) @ ErrorCode::OnlyRelayer)]
     pub relayer: Signer<'info>,
 
     /// Mint del token (mediano) -> mover a heap
@@ -39,9 +46,42 @@ pub struct ReleaseSpl<'info> {
     pub recipient: UncheckedAccount<'info>,
 
     /// Config global (autoriza al relayer)
-    #[account(seeds=[b"config"], bump = config.bump)]
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_DELIVERIES) @ ErrorCode::DeliveriesPaused
+    )]
     pub config: Account<'info, BridgeConfig>,
 
+    /// Replay guard: one receipt per (source chain, source request)
+    #[account(
+        init,
+        payer = relayer,
+        space = 8 + size_of::<DeliveryReceipt>(),
+        seeds = [b"delivery", &source_chain_id.to_le_bytes(), source_request_id.as_ref()],
+        bump
+    )]
+    pub delivery_receipt: Box<Account<'info, DeliveryReceipt>>,
+
+    /// Relayer votes for this delivery; may be omitted while the threshold is 1.
+    /// Closed to the executing relayer.
+    #[account(
+        mut,
+        close = relayer,
+        seeds = [b"votes", action_votes.digest.as_ref()],
+        bump = action_votes.bump
+    )]
+    pub action_votes: Option<Box<Account<'info, ActionVotes>>>,
+
+    /// Off-chain attesters; with the instructions sysvar, their signed digests count as votes
+    #[account(seeds = [b"attesters"], bump = attester_set.bump)]
+    pub attester_set: Option<Box<Account<'info, AttesterSet>>>,
+
+    /// Instructions sysvar, read for the secp256k1 / ed25519 precompile instructions
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: validated by constraint
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
     // Programas
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -57,7 +97,36 @@ pub struct ReleaseSpl<'info> {
     pub sign_pda_account: Account<'info, SignerAccount>,
 }
 
-pub fn handler(ctx: Context<ReleaseSpl>, amount: u64) -> Result<()> {
+pub fn handler(
+    ctx: Context<ReleaseSpl>,
+    source_chain_id: u64,
+    source_request_id: [u8; 32],
+    source_tx_hash: [u8; 32],
+    amount: u64,
+) -> Result<()> {
+    let digest = delivery_digest(
+        source_chain_id,
+        &source_request_id,
+        &source_tx_hash,
+        &ctx.accounts.recipient.key(),
+        &ctx.accounts.mint.key(),
+        amount,
+    );
+    let attestations = count_attestations(
+        ctx.accounts.attester_set.as_deref().map(|s| &**s),
+        ctx.accounts.instructions_sysvar.as_deref(),
+        &digest,
+    )?;
+    require_quorum(
+        &ctx.accounts.config,
+        ctx.accounts.action_votes.as_deref().map(|v| &**v),
+        &digest,
+        VOTE_KIND_DELIVERY,
+        &ctx.accounts.relayer.key(),
+        attestations,
+        Clock::get()?.unix_timestamp,
+    )?;
+
     // Chequeo defensivo (opcional)
     require!(
         ctx.accounts.escrow_token.amount >= amount,
@@ -84,10 +153,24 @@ pub fn handler(ctx: Context<ReleaseSpl>, amount: u64) -> Result<()> {
         ctx.accounts.mint.decimals,
     )?;
 
+    let receipt = &mut ctx.accounts.delivery_receipt;
+    receipt.source_chain_id = source_chain_id;
+    receipt.source_request_id = source_request_id;
+    receipt.source_tx_hash = source_tx_hash;
+    receipt.recipient = ctx.accounts.recipient.key();
+    receipt.token = ctx.accounts.mint.key();
+    receipt.amount = amount;
+    receipt.relayer = ctx.accounts.relayer.key();
+    receipt.delivered_at = Clock::get()?.unix_timestamp;
+    receipt.bump = ctx.bumps.delivery_receipt;
+
     emit!(IncomingBridgeDelivered {
         recipient: ctx.accounts.recipient.key(),
         token: ctx.accounts.mint.key(),
         amount,
+        source_chain_id,
+        source_request_id,
+        source_tx_hash,
     });
 
     Ok(())
@@ -0,0 +1,123 @@
+use crate::errors::ErrorCode;
+use crate::events::ComputationRequeued;
+use crate::state::{
+    BridgeConfig, BridgeRequest, CircuitRegistry, CircuitVersion, RequestStatus,
+    CIRCUIT_PLAN_PAYOUT, PAUSE_DEPOSITS,
+};
+use crate::{SignerAccount, ID, ID_CONST};
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+/// Queues `plan_payout` again for a request whose attestation aborted, reusing the
+/// ciphertext stored at deposit. Only the payer can requeue (it pays the computation).
+#[queue_computation_accounts("plan_payout", payer)]
+#[derive(Accounts)]
+#[instruction(request_id: u64, computation_offset: u64)]
+pub struct RequeuePlanPayout<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_DEPOSITS) @ ErrorCode::DepositsPaused
+    )]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"request", payer.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump,
+        constraint = request_pda.status == RequestStatus::AttestationFailed
+            @ ErrorCode::AttestationNotFailed
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, SignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    #[account(mut, address = derive_mempool_pda!())]
+    /// CHECK: validated by Arcium
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(mut, address = derive_execpool_pda!())]
+    /// CHECK: validated by Arcium
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(mut, address = derive_comp_pda!(computation_offset))]
+    /// CHECK: validated by Arcium
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"circuit", &[CIRCUIT_PLAN_PAYOUT]], bump = circuit_registry.bump)]
+    pub circuit_registry: Box<Account<'info, CircuitRegistry>>,
+
+    /// Active version; handed to the callback so it accepts this comp-def
+    #[account(
+        seeds = [b"circuit_version", &[CIRCUIT_PLAN_PAYOUT], &circuit_registry.active_version.to_le_bytes()],
+        bump = circuit_version.bump
+    )]
+    pub circuit_version: Box<Account<'info, CircuitVersion>>,
+
+    #[account(address = derive_comp_def_pda!(circuit_version.comp_def_offset))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+pub fn handler(
+    ctx: Context<RequeuePlanPayout>,
+    request_id: u64,
+    computation_offset: u64,
+) -> Result<()> {
+    let req = &mut ctx.accounts.request_pda;
+    req.transition(RequestStatus::Pending)?;
+
+    let args = vec![
+        Argument::ArcisPubkey(req.client_pubkey),
+        Argument::PlaintextU128(req.nonce_le),
+        Argument::EncryptedU64(req.dest_ct_w0),
+        Argument::EncryptedU64(req.dest_ct_w1),
+        Argument::EncryptedU64(req.dest_ct_w2),
+        Argument::EncryptedU64(req.dest_ct_w3),
+    ];
+    let callback = super::callback::PlanPayoutCallback::callback_ix(&[
+        CallbackAccount {
+            pubkey: req.key(),
+            is_writable: true,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.circuit_version.key(),
+            is_writable: false,
+        },
+    ]);
+
+    emit!(ComputationRequeued {
+        request_id,
+        circuit: CIRCUIT_PLAN_PAYOUT,
+        computation_offset,
+        failures: req.compute_failures,
+    });
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+    queue_computation(ctx.accounts, computation_offset, args, None, vec![callback])
+}
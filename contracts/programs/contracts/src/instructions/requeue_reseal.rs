@@ -0,0 +1,155 @@
+use crate::errors::ErrorCode;
+use crate::events::ComputationRequeued;
+use crate::state::{
+    BridgeConfig, BridgeRequest, CircuitRegistry, CircuitVersion, ClaimReseal, RequestStatus,
+    ResealStatus, CIRCUIT_RESEAL, PAUSE_CLAIMS,
+};
+use crate::{SignerAccount, ID, ID_CONST};
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+/// Queues `reseal_destination` again for the active claim after the previous one aborted.
+/// The claim window restarts, since the solver could not act without the destination.
+#[queue_computation_accounts("reseal_destination", solver)]
+#[derive(Accounts)]
+#[instruction(request_id: u64, computation_offset: u64)]
+pub struct RequeueReseal<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_CLAIMS) @ ErrorCode::ClaimsPaused
+    )]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"request", request_owner.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    /// CHECK: seeds-only
+    pub request_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"reseal", request_pda.key().as_ref()],
+        bump = claim_reseal.bump,
+        constraint = claim_reseal.status == ResealStatus::Failed @ ErrorCode::ResealNotFailed
+    )]
+    pub claim_reseal: Box<Account<'info, ClaimReseal>>,
+
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = solver,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, SignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(mut, address = derive_mempool_pda!())]
+    /// CHECK: validated by Arcium
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(mut, address = derive_execpool_pda!())]
+    /// CHECK: validated by Arcium
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(mut, address = derive_comp_pda!(computation_offset))]
+    /// CHECK: validated by Arcium
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"circuit", &[CIRCUIT_RESEAL]], bump = circuit_registry.bump)]
+    pub circuit_registry: Box<Account<'info, CircuitRegistry>>,
+
+    /// Active version; handed to the callback so it accepts this comp-def
+    #[account(
+        seeds = [b"circuit_version", &[CIRCUIT_RESEAL], &circuit_registry.active_version.to_le_bytes()],
+        bump = circuit_version.bump
+    )]
+    pub circuit_version: Box<Account<'info, CircuitVersion>>,
+
+    #[account(address = derive_comp_def_pda!(circuit_version.comp_def_offset))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RequeueReseal>,
+    request_id: u64,
+    computation_offset: u64,
+) -> Result<()> {
+    let req = &mut ctx.accounts.request_pda;
+    require!(req.status == RequestStatus::Claimed, ErrorCode::NoClaim);
+    require_keys_eq!(
+        ctx.accounts.solver.key(),
+        req.solver,
+        ErrorCode::InvalidOwner
+    );
+    require_keys_eq!(
+        ctx.accounts.claim_reseal.solver,
+        req.solver,
+        ErrorCode::ResealNotFailed
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    req.claim_deadline = now
+        .checked_add(req.claim_window_secs)
+        .ok_or(ErrorCode::MathOverflow)?
+        .max(req.claim_deadline);
+
+    let reseal = &mut ctx.accounts.claim_reseal;
+    reseal.status = ResealStatus::Queued;
+
+    let args = vec![
+        Argument::ArcisPubkey(reseal.solver_x25519),
+        Argument::PlaintextU128(req.nonce_le),
+        Argument::EncryptedU64(req.dest_ct_w0),
+        Argument::EncryptedU64(req.dest_ct_w1),
+        Argument::EncryptedU64(req.dest_ct_w2),
+        Argument::EncryptedU64(req.dest_ct_w3),
+    ];
+    let callback = super::reseal_callback::ResealDestinationCallback::callback_ix(&[
+        CallbackAccount {
+            pubkey: req.key(),
+            is_writable: true,
+        },
+        CallbackAccount {
+            pubkey: reseal.key(),
+            is_writable: true,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.circuit_version.key(),
+            is_writable: false,
+        },
+    ]);
+
+    emit!(ComputationRequeued {
+        request_id,
+        circuit: CIRCUIT_RESEAL,
+        computation_offset,
+        failures: req.compute_failures,
+    });
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+    queue_computation(ctx.accounts, computation_offset, args, None, vec![callback])
+}
@@ -0,0 +1,102 @@
+use crate::events::{DestinationResealFailed, DestinationResealed};
+use crate::state::{
+    BridgeRequest, CircuitVersion, ClaimReseal, RequestStatus, ResealStatus, CIRCUIT_RESEAL,
+};
+
+use crate::ID_CONST;
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+#[callback_accounts("reseal_destination")]
+#[derive(Accounts)]
+pub struct ResealDestinationCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(circuit_version.comp_def_offset))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: validated by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Claimed request (writable callback account: failure counter)
+    #[account(
+        mut,
+        seeds = [b"request", request_pda.payer.as_ref(), &request_pda.request_id.to_le_bytes()],
+        bump = request_pda.bump
+    )]
+    pub request_pda: Account<'info, BridgeRequest>,
+
+    /// Where the resealed destination is delivered (writable callback account)
+    #[account(
+        mut,
+        seeds = [b"reseal", request_pda.key().as_ref()],
+        bump = claim_reseal.bump
+    )]
+    pub claim_reseal: Account<'info, ClaimReseal>,
+
+    /// Version the computation was queued under, so in-flight computations survive a
+    /// `set_active_circuit_version` (read-only callback account)
+    #[account(
+        seeds = [b"circuit_version", &[CIRCUIT_RESEAL], &circuit_version.version.to_le_bytes()],
+        bump = circuit_version.bump
+    )]
+    pub circuit_version: Account<'info, CircuitVersion>,
+}
+
+pub fn handler(
+    ctx: Context<ResealDestinationCallback>,
+    output: ComputationOutputs<ResealDestinationOutput>,
+) -> Result<()> {
+    let req = &mut ctx.accounts.request_pda;
+    let reseal = &mut ctx.accounts.claim_reseal;
+
+    // Claim released/settled (or re-claimed) while the MPC was running: nothing to deliver
+    if req.status != RequestStatus::Claimed
+        || reseal.solver != req.solver
+        || reseal.status != ResealStatus::Queued
+    {
+        msg!("reseal:skip status={:?}", req.status);
+        return Ok(());
+    }
+
+    let o = match output {
+        ComputationOutputs::Success(ResealDestinationOutput { field_0 }) => field_0,
+        _ => {
+            // Ok (not Err) so the failure is persisted: requeue_reseal or release_failed_claim
+            reseal.status = ResealStatus::Failed;
+            req.compute_failures = req.compute_failures.saturating_add(1);
+            emit!(DestinationResealFailed {
+                request_id: req.request_id,
+                solver: reseal.solver,
+            });
+            return Ok(());
+        }
+    };
+
+    // Output of an earlier claim that resealed to another key
+    if o.encryption_key != reseal.solver_x25519 {
+        msg!("reseal:skip stale key");
+        return Ok(());
+    }
+
+    let [w0, w1, w2, w3] = o.ciphertexts;
+    reseal.status = ResealStatus::Delivered;
+    reseal.nonce = o.nonce;
+    reseal.ct_w0 = w0;
+    reseal.ct_w1 = w1;
+    reseal.ct_w2 = w2;
+    reseal.ct_w3 = w3;
+    reseal.resealed_at = Clock::get()?.unix_timestamp;
+
+    emit!(DestinationResealed {
+        request_id: req.request_id,
+        solver: reseal.solver,
+        nonce: o.nonce.to_le_bytes(),
+        ct_w0: w0,
+        ct_w1: w1,
+        ct_w2: w2,
+        ct_w3: w3,
+    });
+    Ok(())
+}
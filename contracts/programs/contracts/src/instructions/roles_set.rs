@@ -0,0 +1,50 @@
+use crate::errors::ErrorCode;
+use crate::events::{FeeCollectorUpdated, PauserUpdated, SlashCollectorUpdated};
+use crate::state::BridgeConfig;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRoles<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+}
+
+pub fn handler(
+    ctx: Context<SetRoles>,
+    fee_collector: Option<Pubkey>,
+    slash_collector: Option<Pubkey>,
+    pauser: Option<Pubkey>,
+) -> Result<()> {
+    let cfg = &mut ctx.accounts.config;
+
+    if let Some(v) = fee_collector {
+        emit!(FeeCollectorUpdated {
+            previous: cfg.fee_collector,
+            fee_collector: v,
+        });
+        cfg.fee_collector = v;
+    }
+    if let Some(v) = slash_collector {
+        emit!(SlashCollectorUpdated {
+            previous: cfg.slash_collector,
+            slash_collector: v,
+        });
+        cfg.slash_collector = v;
+    }
+    if let Some(v) = pauser {
+        emit!(PauserUpdated {
+            previous: cfg.pauser,
+            pauser: v,
+        });
+        cfg.pauser = v;
+    }
+
+    Ok(())
+}
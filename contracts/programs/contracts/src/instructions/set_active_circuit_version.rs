@@ -0,0 +1,50 @@
+use crate::errors::ErrorCode;
+use crate::state::{BridgeConfig, CircuitRegistry, CircuitVersion};
+use crate::ID_CONST;
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use super::register_circuit_version::activate;
+
+/// Switches the comp-def the queue paths submit to. The target comp-def must already be
+/// initialized in Arcium, otherwise the next deposit/claim would fail to queue. Computations
+/// queued under the previous version still call back through their own `CircuitVersion`.
+#[derive(Accounts)]
+#[instruction(circuit: u8, version: u32)]
+pub struct SetActiveCircuitVersion<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"circuit", &[circuit]],
+        bump = circuit_registry.bump
+    )]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    #[account(
+        seeds = [b"circuit_version", &[circuit], &version.to_le_bytes()],
+        bump = circuit_version.bump
+    )]
+    pub circuit_version: Account<'info, CircuitVersion>,
+
+    #[account(address = derive_comp_def_pda!(circuit_version.comp_def_offset))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+}
+
+pub fn handler(ctx: Context<SetActiveCircuitVersion>, _circuit: u8, version: u32) -> Result<()> {
+    let registry = &mut ctx.accounts.circuit_registry;
+    require!(
+        registry.active_version != version,
+        ErrorCode::CircuitVersionAlreadyActive
+    );
+
+    activate(registry, &ctx.accounts.circuit_version);
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::errors::ErrorCode;
+use crate::events::SolverAuthorized;
+use crate::state::{BridgeConfig, SolverEntry};
+use anchor_lang::prelude::*;
+use core::mem::size_of;
+
+/// Adds a solver (or rotates its x25519 key if already registered).
+#[derive(Accounts)]
+#[instruction(solver: Pubkey)]
+pub struct AuthorizeSolver<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + size_of::<SolverEntry>(),
+        seeds = [b"solver", solver.as_ref()],
+        bump
+    )]
+    pub solver_entry: Account<'info, SolverEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AuthorizeSolver>, solver: Pubkey, x25519: [u8; 32]) -> Result<()> {
+    let entry = &mut ctx.accounts.solver_entry;
+
+    entry.solver = solver;
+    entry.x25519 = x25519;
+    entry.authorized_at = Clock::get()?.unix_timestamp;
+    entry.bump = ctx.bumps.solver_entry;
+
+    emit!(SolverAuthorized { solver, x25519 });
+    Ok(())
+}
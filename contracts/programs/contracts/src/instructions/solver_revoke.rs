@@ -0,0 +1,33 @@
+use crate::errors::ErrorCode;
+use crate::events::SolverRevoked;
+use crate::state::{BridgeConfig, SolverEntry};
+use anchor_lang::prelude::*;
+
+/// Removes a solver (EVM `revokeNode`). Claims it already holds are not affected.
+#[derive(Accounts)]
+pub struct RevokeSolver<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"solver", solver_entry.solver.as_ref()],
+        bump = solver_entry.bump
+    )]
+    pub solver_entry: Account<'info, SolverEntry>,
+}
+
+pub fn handler(ctx: Context<RevokeSolver>) -> Result<()> {
+    emit!(SolverRevoked {
+        solver: ctx.accounts.solver_entry.solver,
+    });
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use crate::errors::ErrorCode;
+use crate::events::TokenConfigUpdated;
+use crate::state::{BridgeConfig, TokenConfig};
+use crate::validation::validate_token_config;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use core::mem::size_of;
+
+/// Allowlists a mint. Fee fields left as `None` fall back to the global defaults in config.
+#[derive(Accounts)]
+pub struct InitTokenConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + size_of::<TokenConfig>(),
+        seeds = [b"token_config", mint.key().as_ref()],
+        bump
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitTokenConfig>,
+    enabled: bool,
+    min_deposit: u64,
+    max_deposit: u64,
+    fee_bps: Option<u16>,
+    min_fee: Option<u64>,
+    max_fee: Option<u64>,
+) -> Result<()> {
+    let cfg = &ctx.accounts.config;
+    let tc = &mut ctx.accounts.token_config;
+
+    tc.mint = ctx.accounts.mint.key();
+    tc.enabled = enabled;
+    tc.decimals = ctx.accounts.mint.decimals;
+    tc.min_deposit = min_deposit;
+    tc.max_deposit = max_deposit;
+    tc.fee_bps = fee_bps.unwrap_or(cfg.fee_bps);
+    tc.min_fee = min_fee.unwrap_or(cfg.min_fee);
+    tc.max_fee = max_fee.unwrap_or(cfg.max_fee);
+    tc.version = 1;
+    tc.bump = ctx.bumps.token_config;
+    validate_token_config(tc)?;
+
+    emit!(TokenConfigUpdated {
+        mint: tc.mint,
+        version: tc.version,
+        enabled: tc.enabled,
+    });
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use crate::errors::ErrorCode;
+use crate::events::TokenConfigUpdated;
+use crate::state::{BridgeConfig, TokenConfig};
+use crate::validation::validate_token_config;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetTokenConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"token_config", token_config.mint.as_ref()],
+        bump = token_config.bump
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+}
+
+pub fn handler(
+    ctx: Context<SetTokenConfig>,
+    enabled: Option<bool>,
+    min_deposit: Option<u64>,
+    max_deposit: Option<u64>,
+    fee_bps: Option<u16>,
+    min_fee: Option<u64>,
+    max_fee: Option<u64>,
+) -> Result<()> {
+    let tc = &mut ctx.accounts.token_config;
+    let schedule_before = tc.fee_schedule();
+
+    if let Some(v) = enabled {
+        tc.enabled = v;
+    }
+    if let Some(v) = min_deposit {
+        tc.min_deposit = v;
+    }
+    if let Some(v) = max_deposit {
+        tc.max_deposit = v;
+    }
+    if let Some(v) = fee_bps {
+        tc.fee_bps = v;
+    }
+    if let Some(v) = min_fee {
+        tc.min_fee = v;
+    }
+    if let Some(v) = max_fee {
+        tc.max_fee = v;
+    }
+    validate_token_config(tc)?;
+
+    // New requests record the version they were priced against; limits and the enabled
+    // flag don't change the price, so they keep the version
+    if tc.fee_schedule() != schedule_before {
+        tc.version = tc.version.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    emit!(TokenConfigUpdated {
+        mint: tc.mint,
+        version: tc.version,
+        enabled: tc.enabled,
+    });
+    Ok(())
+}
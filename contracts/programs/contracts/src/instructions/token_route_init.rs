@@ -0,0 +1,62 @@
+use crate::errors::ErrorCode;
+use crate::events::TokenRouteUpdated;
+use crate::state::{BridgeConfig, TokenConfig, TokenRoute};
+use crate::validation::validate_token_route;
+use anchor_lang::prelude::*;
+use core::mem::size_of;
+
+/// Registers where `mint` lands on `dest_chain_id`. The mint must already have a TokenConfig.
+#[derive(Accounts)]
+#[instruction(dest_chain_id: u64)]
+pub struct InitTokenRoute<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(seeds = [b"token_config", token_config.mint.as_ref()], bump = token_config.bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + size_of::<TokenRoute>(),
+        seeds = [b"token_route", token_config.mint.as_ref(), &dest_chain_id.to_le_bytes()],
+        bump
+    )]
+    pub token_route: Account<'info, TokenRoute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitTokenRoute>,
+    dest_chain_id: u64,
+    dest_token: [u8; 20],
+    dest_decimals: u8,
+    enabled: bool,
+) -> Result<()> {
+    let route = &mut ctx.accounts.token_route;
+
+    route.mint = ctx.accounts.token_config.mint;
+    route.dest_chain_id = dest_chain_id;
+    route.dest_token = dest_token;
+    route.dest_decimals = dest_decimals;
+    route.enabled = enabled;
+    route.bump = ctx.bumps.token_route;
+    validate_token_route(route, ctx.accounts.token_config.decimals)?;
+
+    emit!(TokenRouteUpdated {
+        mint: route.mint,
+        dest_chain_id,
+        dest_token,
+        dest_decimals,
+        enabled,
+    });
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use crate::errors::ErrorCode;
+use crate::events::TokenRouteUpdated;
+use crate::state::{BridgeConfig, TokenConfig, TokenRoute};
+use crate::validation::validate_token_route;
+use anchor_lang::prelude::*;
+
+/// Updates a route. Requests already created keep the destination token and amount they recorded.
+#[derive(Accounts)]
+pub struct SetTokenRoute<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::OnlyOwner
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(seeds = [b"token_config", token_route.mint.as_ref()], bump = token_config.bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_route",
+            token_route.mint.as_ref(),
+            &token_route.dest_chain_id.to_le_bytes()
+        ],
+        bump = token_route.bump
+    )]
+    pub token_route: Account<'info, TokenRoute>,
+}
+
+pub fn handler(
+    ctx: Context<SetTokenRoute>,
+    dest_token: Option<[u8; 20]>,
+    dest_decimals: Option<u8>,
+    enabled: Option<bool>,
+) -> Result<()> {
+    let route = &mut ctx.accounts.token_route;
+
+    if let Some(v) = dest_token {
+        route.dest_token = v;
+    }
+    if let Some(v) = dest_decimals {
+        route.dest_decimals = v;
+    }
+    if let Some(v) = enabled {
+        route.enabled = v;
+    }
+    validate_token_route(route, ctx.accounts.token_config.decimals)?;
+
+    emit!(TokenRouteUpdated {
+        mint: route.mint,
+        dest_chain_id: route.dest_chain_id,
+        dest_token: route.dest_token,
+        dest_decimals: route.dest_decimals,
+        enabled: route.enabled,
+    });
+    Ok(())
+}
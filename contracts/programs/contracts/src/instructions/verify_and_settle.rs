@@ -1,31 +1,45 @@
+use crate::attestation::count_attestations;
+use crate::digest::settlement_digest;
 use crate::errors::ErrorCode;
 use crate::events::{BridgePaidToSolver, BridgeVerifiedUrl};
-use crate::instructions::claim_bridge::BOND_VAULT_SEED;
-use crate::state::{BridgeConfig, BridgeRequest};
-use crate::{SignerAccount, ID_CONST};
+use crate::instructions::claim_bridge::{take_bond, BOND_VAULT_SEED};
+use crate::state::{
+    require_quorum, ActionVotes, AttesterSet, BridgeConfig, BridgeRequest, FeeVault, RequestStatus,
+    PAUSE_SETTLEMENTS, VOTE_KIND_SETTLEMENT,
+};
 
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self as token, Mint, Token, TokenAccount, TransferChecked};
-use arcium_anchor::prelude::*;
+use core::mem::size_of;
 
 /// Verifies off-chain and settles on-chain.
-/// Authorization: only the relayer (we use `config.owner`).
+/// Authorization: any key in the relayer set, once `config.quorum()` approvals are in:
+/// `vote_settlement` votes, attester signatures in the same transaction, and the executing
+/// relayer itself.
+/// The evidence must name the destination token recorded at deposit and at least the expected amount.
 #[derive(Accounts)]
 #[instruction(request_id: u64)]
 pub struct VerifyAndSettleSpl<'info> {
-    /// Relayer/verifier. Must be the configured owner.
-    #[account(mut, address = config.owner)]
+    /// Relayer/verifier. Must be in the configured relayer set.
+    #[account(mut, constraint = config.is_relayer(&relayer.key()) @ ErrorCode::OnlyRelayer)]
     pub relayer: Signer<'info>,
 
     /// Global config (pequeña)
-    #[account(seeds=[b"config"], bump = config.bump)]
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_SETTLEMENTS) @ ErrorCode::SettlementsPaused
+    )]
     pub config: Account<'info, BridgeConfig>,
 
     /// Request to settle (PUEDE SER GRANDE) -> Box para evitar copiar al stack
     #[account(
         mut,
         seeds = [b"request", request_owner.key().as_ref(), &request_id.to_le_bytes()],
-        bump = request_pda.bump
+        bump = request_pda.bump,
+        constraint = request_pda.bond_bump != 0 @ ErrorCode::LegacyBondVault,
+        constraint = !request_pda.legacy_escrow @ ErrorCode::LegacyEscrow
     )]
     pub request_pda: Box<Account<'info, BridgeRequest>>,
 
@@ -33,14 +47,34 @@ pub struct VerifyAndSettleSpl<'info> {
     /// CHECK: seeds-only
     pub request_owner: UncheckedAccount<'info>,
 
+    /// Relayer votes for this settlement; may be omitted while the threshold is 1.
+    /// Closed to the executing relayer.
+    #[account(
+        mut,
+        close = relayer,
+        seeds = [b"votes", action_votes.digest.as_ref()],
+        bump = action_votes.bump
+    )]
+    pub action_votes: Option<Box<Account<'info, ActionVotes>>>,
+
+    /// Off-chain attesters; with the instructions sysvar, their signed digests count as votes
+    #[account(seeds = [b"attesters"], bump = attester_set.bump)]
+    pub attester_set: Option<Box<Account<'info, AttesterSet>>>,
+
+    /// Instructions sysvar, read for the secp256k1 / ed25519 precompile instructions
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: validated by constraint
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
     /// Mint del token (mediana) -> Box
+    #[account(address = request_pda.token_mint @ ErrorCode::InvalidMint)]
     pub mint: Box<Account<'info, Mint>>,
 
-    /// Vault con los tokens (owner = PDA signer) (mediana) -> Box
+    /// Escrow of this request (ATA owned by the request PDA) -> Box
     #[account(
         mut,
-        constraint = escrow_token.mint == mint.key() @ ErrorCode::InvalidMint,
-        constraint = escrow_token.owner == sign_pda_account.key() @ ErrorCode::InvalidOwner
+        associated_token::mint = mint,
+        associated_token::authority = request_pda,
     )]
     pub escrow_token: Box<Account<'info, TokenAccount>>,
 
@@ -52,22 +86,33 @@ pub struct VerifyAndSettleSpl<'info> {
     )]
     pub solver_token: Box<Account<'info, TokenAccount>>,
 
-    /// PDA signer (misma que en deposit)
+    /// Protocol treasury of this mint (created on first settle)
     #[account(
-        mut,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
+        init_if_needed,
+        payer = relayer,
+        space = 8 + size_of::<FeeVault>(),
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: Box<Account<'info, FeeVault>>,
+
+    /// Tokens of the fee vault (ATA owned by the fee vault PDA)
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        associated_token::mint = mint,
+        associated_token::authority = fee_vault,
     )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
+    pub fee_vault_token: Box<Account<'info, TokenAccount>>,
 
-    /// Vault (System) con el bond del solver
+    /// Vault con el bond del solver
+    /// CHECK: program-owned, no data; validated by seeds
     #[account(
         mut,
-        seeds = [BOND_VAULT_SEED, &request_id.to_le_bytes()],
-        bump
+        seeds = [BOND_VAULT_SEED, request_pda.key().as_ref()],
+        bump = request_pda.bond_bump
     )]
-    pub bond_vault: SystemAccount<'info>,
+    pub bond_vault: UncheckedAccount<'info>,
 
     /// Wallet del solver (para devolver bond)
     /// CHECK: se valida contra request_pda.solver
@@ -75,6 +120,7 @@ pub struct VerifyAndSettleSpl<'info> {
     pub solver_wallet: UncheckedAccount<'info>,
 
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
@@ -84,31 +130,39 @@ pub fn handler(
     dest_tx_hash: [u8; 32],
     evidence_hash: [u8; 32],
     evidence_url: String,
+    dest_token: [u8; 20],
+    dest_amount: u128,
 ) -> Result<()> {
     let req = &mut ctx.accounts.request_pda;
-
-    // --- checks ---
-    require!(!req.finalized, ErrorCode::RequestAlreadyFinalized);
-    require!(req.claimed, ErrorCode::NoClaim);
-
     let now = Clock::get()?.unix_timestamp;
-    require!(now <= req.claim_deadline, ErrorCode::ClaimExpired);
-
-    require_keys_eq!(
-        ctx.accounts.solver_wallet.key(),
-        req.solver,
-        ErrorCode::InvalidOwner
-    );
-
-    // --- payout = net + fee ---
-    let payout = req
-        .amount_locked
-        .checked_add(req.fee_locked)
-        .ok_or(ErrorCode::MathOverflow)?;
+    let digest = settlement_digest(req, &dest_tx_hash, &evidence_hash, &dest_token, dest_amount);
+    let attestations = count_attestations(
+        ctx.accounts.attester_set.as_deref().map(|s| &**s),
+        ctx.accounts.instructions_sysvar.as_deref(),
+        &digest,
+    )?;
+    require_quorum(
+        &ctx.accounts.config,
+        ctx.accounts.action_votes.as_deref().map(|v| &**v),
+        &digest,
+        VOTE_KIND_SETTLEMENT,
+        &ctx.accounts.relayer.key(),
+        attestations,
+        now,
+    )?;
+    let (payout, protocol_fee) = settlement_amounts(
+        req,
+        &ctx.accounts.solver_wallet.key(),
+        now,
+        dest_token,
+        dest_amount,
+    )?;
 
-    // --- SPL transfer (escrow -> solver) firmado por la PDA ---
-    let bump = ctx.bumps.sign_pda_account;
-    let signer_seeds: &[&[u8]] = &[&SIGN_PDA_SEED, &[bump]];
+    // --- SPL transfer (escrow -> solver) firmado por la request PDA ---
+    let owner_key = ctx.accounts.request_owner.key();
+    let id_bytes = request_id.to_le_bytes();
+    let bump = [req.bump];
+    let signer_seeds: &[&[u8]] = &[b"request", owner_key.as_ref(), &id_bytes, &bump];
 
     token::transfer_checked(
         CpiContext::new_with_signer(
@@ -117,7 +171,7 @@ pub fn handler(
                 from: ctx.accounts.escrow_token.to_account_info(),
                 mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.solver_token.to_account_info(),
-                authority: ctx.accounts.sign_pda_account.to_account_info(),
+                authority: req.to_account_info(),
             },
             &[signer_seeds],
         ),
@@ -125,27 +179,39 @@ pub fn handler(
         ctx.accounts.mint.decimals,
     )?;
 
-    // --- devolver bond ---
-    let bond = req.bond_lamports;
-    if bond > 0 {
-        **ctx
-            .accounts
-            .bond_vault
-            .to_account_info()
-            .try_borrow_mut_lamports()? -= bond;
-        **ctx
-            .accounts
-            .solver_wallet
-            .to_account_info()
-            .try_borrow_mut_lamports()? += bond;
+    if protocol_fee > 0 {
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.escrow_token.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.fee_vault_token.to_account_info(),
+                    authority: req.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            protocol_fee,
+            ctx.accounts.mint.decimals,
+        )?;
     }
 
-    // --- finalize ---
-    req.finalized = true;
-    req.claimed = false;
-    req.solver = Pubkey::default();
-    req.claim_deadline = 0;
-    req.bond_lamports = 0;
+    let vault = &mut ctx.accounts.fee_vault;
+    if vault.mint == Pubkey::default() {
+        vault.mint = ctx.accounts.mint.key();
+        vault.bump = ctx.bumps.fee_vault;
+    }
+    vault.collected_fees = vault
+        .collected_fees
+        .checked_add(protocol_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    finish_settlement(
+        req,
+        &ctx.accounts.bond_vault.to_account_info(),
+        &ctx.accounts.solver_wallet.to_account_info(),
+        now,
+    )?;
 
     // --- eventos ---
     emit!(BridgeVerifiedUrl {
@@ -154,6 +220,7 @@ pub fn handler(
         dest_tx_hash,
         evidence_hash,
         evidence_url,
+        dest_amount,
     });
 
     emit!(BridgePaidToSolver {
@@ -161,7 +228,79 @@ pub fn handler(
         solver: ctx.accounts.solver_wallet.key(),
         token_mint: ctx.accounts.mint.key(),
         payout,
+        protocol_fee,
     });
 
     Ok(())
 }
+
+/// Checks shared by both settle paths. Returns (payout to the solver, protocol fee).
+pub(crate) fn settlement_amounts(
+    req: &BridgeRequest,
+    solver_wallet: &Pubkey,
+    now: i64,
+    dest_token: [u8; 20],
+    dest_amount: u128,
+) -> Result<(u64, u64)> {
+    // --- checks ---
+    require!(req.status == RequestStatus::Claimed, ErrorCode::NoClaim);
+    require!(now <= req.claim_deadline, ErrorCode::ClaimExpired);
+    require_keys_eq!(*solver_wallet, req.solver, ErrorCode::InvalidOwner);
+
+    // --- evidence must match what the deposit resolved ---
+    require!(req.dest_token == dest_token, ErrorCode::DestTokenMismatch);
+    require!(
+        dest_amount >= req.expected_dest_amount,
+        ErrorCode::DestAmountTooLow
+    );
+
+    // --- fee split: protocol share to the fee vault, the rest (plus net) to the solver ---
+    let protocol_fee =
+        u64::try_from((req.fee_locked as u128 * req.protocol_fee_bps as u128) / 10_000)
+            .map_err(|_| ErrorCode::MathOverflow)?;
+    let solver_fee = req
+        .fee_locked
+        .checked_sub(protocol_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let payout = req
+        .amount_locked
+        .checked_add(solver_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+    Ok((payout, protocol_fee))
+}
+
+/// Returns the bond and the destination gas fee to the solver and marks the request Settled.
+pub(crate) fn finish_settlement<'info>(
+    req: &mut Account<'info, BridgeRequest>,
+    bond_vault: &AccountInfo<'info>,
+    solver_wallet: &AccountInfo<'info>,
+    now: i64,
+) -> Result<()> {
+    // --- devolver bond ---
+    let bond = req.bond_lamports;
+    if bond > 0 {
+        take_bond(
+            bond_vault,
+            bond,
+            Rent::get()?.minimum_balance(0),
+            ErrorCode::MathOverflow,
+        )?;
+        **solver_wallet.try_borrow_mut_lamports()? += bond;
+    }
+
+    // --- destination gas fee (parked on the request PDA) goes to the solver ---
+    let gas_fee = req.dest_gas_fee_lamports;
+    if gas_fee > 0 {
+        **req.to_account_info().try_borrow_mut_lamports()? -= gas_fee;
+        **solver_wallet.try_borrow_mut_lamports()? += gas_fee;
+        req.dest_gas_fee_lamports = 0;
+    }
+
+    // --- finalize ---
+    req.transition(RequestStatus::Settled)?;
+    req.finalized_at = now;
+    req.solver = Pubkey::default();
+    req.claim_deadline = 0;
+    req.bond_lamports = 0;
+    Ok(())
+}
@@ -0,0 +1,258 @@
+use crate::attestation::count_attestations;
+use crate::digest::settlement_digest;
+use crate::errors::ErrorCode;
+use crate::events::{BridgePaidToSolver, BridgeVerifiedUrl};
+use crate::instructions::claim_bridge::BOND_VAULT_SEED;
+use crate::instructions::verify_and_settle::{finish_settlement, settlement_amounts};
+use crate::state::{
+    require_quorum, ActionVotes, AttesterSet, BridgeConfig, BridgeRequest, FeeVault,
+    PAUSE_SETTLEMENTS, VOTE_KIND_SETTLEMENT,
+};
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{
+    self as token, spl_token, CloseAccount, Mint, Token, TokenAccount, TransferChecked,
+};
+use core::mem::size_of;
+
+/// Settlement of a SOL request (`initiate_bridge_sol`): same checks and fee split as
+/// `verify_and_settle_spl`, but the solver is paid in native lamports. The payout is moved
+/// to a temporary WSOL account of the request, which is closed to the relayer (unwrap) and
+/// forwarded to the solver wallet; the relayer keeps only the temp account's rent back.
+/// The protocol fee stays wrapped in the WSOL fee vault.
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct VerifyAndSettleSol<'info> {
+    /// Relayer/verifier. Must be in the configured relayer set.
+    #[account(mut, constraint = config.is_relayer(&relayer.key()) @ ErrorCode::OnlyRelayer)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_SETTLEMENTS) @ ErrorCode::SettlementsPaused
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"request", request_owner.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump,
+        constraint = request_pda.bond_bump != 0 @ ErrorCode::LegacyBondVault,
+        constraint = !request_pda.legacy_escrow @ ErrorCode::LegacyEscrow
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    /// CHECK: seeds-only
+    pub request_owner: UncheckedAccount<'info>,
+
+    /// Relayer votes for this settlement; may be omitted while the threshold is 1.
+    /// Closed to the executing relayer.
+    #[account(
+        mut,
+        close = relayer,
+        seeds = [b"votes", action_votes.digest.as_ref()],
+        bump = action_votes.bump
+    )]
+    pub action_votes: Option<Box<Account<'info, ActionVotes>>>,
+
+    /// Off-chain attesters; with the instructions sysvar, their signed digests count as votes
+    #[account(seeds = [b"attesters"], bump = attester_set.bump)]
+    pub attester_set: Option<Box<Account<'info, AttesterSet>>>,
+
+    /// Instructions sysvar, read for the secp256k1 / ed25519 precompile instructions
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: validated by constraint
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        address = spl_token::native_mint::id() @ ErrorCode::InvalidMint,
+        constraint = request_pda.token_mint == mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// WSOL escrow of this request (ATA owned by the request PDA)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = request_pda,
+    )]
+    pub escrow_token: Box<Account<'info, TokenAccount>>,
+
+    /// Temporary WSOL account the payout is unwrapped from; closed in this instruction
+    #[account(
+        init,
+        payer = relayer,
+        token::mint = mint,
+        token::authority = request_pda,
+        seeds = [b"unwrap", request_pda.key().as_ref()],
+        bump
+    )]
+    pub unwrap_token: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + size_of::<FeeVault>(),
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: Box<Account<'info, FeeVault>>,
+
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        associated_token::mint = mint,
+        associated_token::authority = fee_vault,
+    )]
+    pub fee_vault_token: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: program-owned, no data; validated by seeds
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, request_pda.key().as_ref()],
+        bump = request_pda.bond_bump
+    )]
+    pub bond_vault: UncheckedAccount<'info>,
+
+    /// Receives the native payout, the bond and the gas fee
+    /// CHECK: validated against request_pda.solver
+    #[account(mut)]
+    pub solver_wallet: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<VerifyAndSettleSol>,
+    request_id: u64,
+    dest_tx_hash: [u8; 32],
+    evidence_hash: [u8; 32],
+    evidence_url: String,
+    dest_token: [u8; 20],
+    dest_amount: u128,
+) -> Result<()> {
+    let req = &mut ctx.accounts.request_pda;
+    let now = Clock::get()?.unix_timestamp;
+    let digest = settlement_digest(req, &dest_tx_hash, &evidence_hash, &dest_token, dest_amount);
+    let attestations = count_attestations(
+        ctx.accounts.attester_set.as_deref().map(|s| &**s),
+        ctx.accounts.instructions_sysvar.as_deref(),
+        &digest,
+    )?;
+    require_quorum(
+        &ctx.accounts.config,
+        ctx.accounts.action_votes.as_deref().map(|v| &**v),
+        &digest,
+        VOTE_KIND_SETTLEMENT,
+        &ctx.accounts.relayer.key(),
+        attestations,
+        now,
+    )?;
+    let (payout, protocol_fee) = settlement_amounts(
+        req,
+        &ctx.accounts.solver_wallet.key(),
+        now,
+        dest_token,
+        dest_amount,
+    )?;
+
+    let owner_key = ctx.accounts.request_owner.key();
+    let id_bytes = request_id.to_le_bytes();
+    let bump = [req.bump];
+    let signer_seeds: &[&[u8]] = &[b"request", owner_key.as_ref(), &id_bytes, &bump];
+
+    // --- unwrap: escrow -> temp WSOL, close temp to the relayer, lamports on to the solver ---
+    token::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.escrow_token.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.unwrap_token.to_account_info(),
+                authority: req.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        payout,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.unwrap_token.to_account_info(),
+            destination: ctx.accounts.relayer.to_account_info(),
+            authority: req.to_account_info(),
+        },
+        &[signer_seeds],
+    ))?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.relayer.to_account_info(),
+                to: ctx.accounts.solver_wallet.to_account_info(),
+            },
+        ),
+        payout,
+    )?;
+
+    if protocol_fee > 0 {
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.escrow_token.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.fee_vault_token.to_account_info(),
+                    authority: req.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            protocol_fee,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    let vault = &mut ctx.accounts.fee_vault;
+    if vault.mint == Pubkey::default() {
+        vault.mint = ctx.accounts.mint.key();
+        vault.bump = ctx.bumps.fee_vault;
+    }
+    vault.collected_fees = vault
+        .collected_fees
+        .checked_add(protocol_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    finish_settlement(
+        req,
+        &ctx.accounts.bond_vault.to_account_info(),
+        &ctx.accounts.solver_wallet.to_account_info(),
+        now,
+    )?;
+
+    emit!(BridgeVerifiedUrl {
+        request_id,
+        relayer: ctx.accounts.relayer.key(),
+        dest_tx_hash,
+        evidence_hash,
+        evidence_url,
+        dest_amount,
+    });
+
+    emit!(BridgePaidToSolver {
+        request_id,
+        solver: ctx.accounts.solver_wallet.key(),
+        token_mint: ctx.accounts.mint.key(),
+        payout,
+        protocol_fee,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use crate::digest::delivery_digest;
+use crate::errors::ErrorCode;
+use crate::events::ActionVoted;
+use crate::state::{ActionVotes, BridgeConfig, PAUSE_DELIVERIES, VOTE_KIND_DELIVERY};
+use anchor_lang::prelude::*;
+use core::mem::size_of;
+
+/// Approves `deliver_tokens` / `deliver_tokens_sol` of `amount` of `mint` to `recipient`.
+/// `digest` must be the `delivery_digest` of the arguments; it only keys the votes PDA.
+#[derive(Accounts)]
+#[instruction(digest: [u8; 32])]
+pub struct VoteDelivery<'info> {
+    #[account(mut, constraint = config.is_relayer(&relayer.key()) @ ErrorCode::OnlyRelayer)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_DELIVERIES) @ ErrorCode::DeliveriesPaused
+    )]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: only hashed into the digest
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: only hashed into the digest (WSOL mint for native SOL)
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + size_of::<ActionVotes>(),
+        seeds = [b"votes", digest.as_ref()],
+        bump
+    )]
+    pub action_votes: Box<Account<'info, ActionVotes>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<VoteDelivery>,
+    digest: [u8; 32],
+    source_chain_id: u64,
+    source_request_id: [u8; 32],
+    source_tx_hash: [u8; 32],
+    amount: u64,
+) -> Result<()> {
+    require!(
+        digest
+            == delivery_digest(
+                source_chain_id,
+                &source_request_id,
+                &source_tx_hash,
+                &ctx.accounts.recipient.key(),
+                &ctx.accounts.mint.key(),
+                amount,
+            ),
+        ErrorCode::VoteDigestMismatch
+    );
+
+    let relayer = ctx.accounts.relayer.key();
+    let votes = &mut ctx.accounts.action_votes;
+    votes.cast(
+        &ctx.accounts.config,
+        digest,
+        VOTE_KIND_DELIVERY,
+        relayer,
+        Clock::get()?.unix_timestamp,
+        ctx.bumps.action_votes,
+    )?;
+
+    emit!(ActionVoted {
+        digest,
+        kind: VOTE_KIND_DELIVERY,
+        relayer,
+        votes: votes.vote_count,
+        threshold: votes.required(&ctx.accounts.config),
+        expires_at: votes.expires_at,
+    });
+    Ok(())
+}
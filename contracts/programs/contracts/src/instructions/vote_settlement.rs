@@ -0,0 +1,87 @@
+use crate::digest::settlement_digest;
+use crate::errors::ErrorCode;
+use crate::events::ActionVoted;
+use crate::state::{
+    ActionVotes, BridgeConfig, BridgeRequest, RequestStatus, PAUSE_SETTLEMENTS,
+    VOTE_KIND_SETTLEMENT,
+};
+use anchor_lang::prelude::*;
+use core::mem::size_of;
+
+/// Approves `verify_and_settle_*` for the given evidence. `digest` must be the
+/// `settlement_digest` of the arguments for the current claim; it only keys the votes PDA.
+/// Votes for an earlier claim of the request are rejected (digest mismatch), as are votes
+/// once the current claim has expired.
+#[derive(Accounts)]
+#[instruction(request_id: u64, digest: [u8; 32])]
+pub struct VoteSettlement<'info> {
+    #[account(mut, constraint = config.is_relayer(&relayer.key()) @ ErrorCode::OnlyRelayer)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_SETTLEMENTS) @ ErrorCode::SettlementsPaused
+    )]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        seeds = [b"request", request_owner.key().as_ref(), &request_id.to_le_bytes()],
+        bump = request_pda.bump
+    )]
+    pub request_pda: Box<Account<'info, BridgeRequest>>,
+
+    /// CHECK: seeds-only
+    pub request_owner: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + size_of::<ActionVotes>(),
+        seeds = [b"votes", digest.as_ref()],
+        bump
+    )]
+    pub action_votes: Box<Account<'info, ActionVotes>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<VoteSettlement>,
+    request_id: u64,
+    digest: [u8; 32],
+    dest_tx_hash: [u8; 32],
+    evidence_hash: [u8; 32],
+    dest_token: [u8; 20],
+    dest_amount: u128,
+) -> Result<()> {
+    let req = &ctx.accounts.request_pda;
+    let now = Clock::get()?.unix_timestamp;
+    require!(req.status == RequestStatus::Claimed, ErrorCode::NoClaim);
+    require!(now <= req.claim_deadline, ErrorCode::ClaimExpired);
+    require!(
+        digest == settlement_digest(req, &dest_tx_hash, &evidence_hash, &dest_token, dest_amount),
+        ErrorCode::VoteDigestMismatch
+    );
+
+    let relayer = ctx.accounts.relayer.key();
+    let votes = &mut ctx.accounts.action_votes;
+    votes.cast(
+        &ctx.accounts.config,
+        digest,
+        VOTE_KIND_SETTLEMENT,
+        relayer,
+        now,
+        ctx.bumps.action_votes,
+    )?;
+
+    emit!(ActionVoted {
+        digest,
+        kind: VOTE_KIND_SETTLEMENT,
+        relayer,
+        votes: votes.vote_count,
+        threshold: votes.required(&ctx.accounts.config),
+        expires_at: votes.expires_at,
+    });
+    Ok(())
+}
@@ -6,16 +6,21 @@ use arcium_anchor::prelude::*;
 
 // Types needed in entrypoint signatures
 use crate::instructions::callback::PlanPayoutOutput;
+use crate::instructions::reseal_callback::ResealDestinationOutput;
+use crate::state::SignatureScheme;
 
 // Arcium program IDs (declare once)
 use arcium_client::idl::arcium as arx;
 pub use arx::ID as ARCIUM_PROGRAM_ID;
 
+pub mod attestation;
 pub mod constants;
+pub mod digest;
 pub mod errors;
 pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod validation;
 
 // ===== Tracing helpers (opcionales; se activan con --features trace) =====
 #[cfg(feature = "trace")]
@@ -43,54 +48,175 @@ pub fn cu(_label: &str) {}
 
 // Re-export handlers & Contexts so entrypoints can delegate cleanly
 pub use instructions::{
+    accept_admin_handler,
+    add_attester_handler,
+    add_relayer_handler,
+    attest_destination_handler,
+    authorize_solver_handler,
+    cancel_config_handler,
+    cancel_request_handler,
+    cancel_request_sol_handler,
     claim_request_handler,
+    close_request_handler,
+    collect_fees_handler,
     // deposits
     deposit_and_queue_handler,
     deposit_sol_and_queue_handler,
+    execute_config_handler,
+    freeze_request_handler,
+    init_chain_config_handler,
     init_config_handler,
     // init / queue / callback
     init_plan_payout_comp_def_handler,
     init_request_handler,
     init_reseal_comp_def_handler,
+    init_token_config_handler,
+    init_token_route_handler,
+    migrate_bond_vault_handler,
+    migrate_config_handler,
+    migrate_request_escrow_handler,
+    migrate_request_handler,
     plan_payout_callback_handler,
-    queue_plan_payout_handler,
+    propose_admin_handler,
+    propose_config_handler,
+    // refunds
+    refund_request_handler,
+    refund_request_sol_handler,
+    register_circuit_version_handler,
     release_expired_claim_handler,
+    release_failed_claim_handler,
     release_sol_handler,
     // releases
     release_spl_handler,
+    remove_attester_handler,
+    remove_relayer_handler,
+    requeue_plan_payout_handler,
+    requeue_reseal_handler,
+    reseal_destination_callback_handler,
+    revoke_solver_handler,
+    set_active_circuit_version_handler,
+    set_chain_config_handler,
     set_config_handler,
+    set_pause_handler,
+    set_relayer_threshold_handler,
+    set_roles_handler,
+    set_token_config_handler,
+    set_token_route_handler,
+    verify_and_settle_sol_handler,
     verify_and_settle_spl_handler,
+    vote_delivery_handler,
+    vote_settlement_handler,
+    AcceptAdmin,
+    AddAttester,
+    AddRelayer,
+    AttestDestination,
+    AuthorizeSolver,
+    CancelConfig,
+    CancelRequest,
+    CancelRequestSol,
     ClaimRequest,
+    CloseRequest,
+    CollectFees,
     // Context types
     DepositAndQueue,
     DepositSolAndQueue,
+    ExecuteConfig,
+    FreezeRequest,
+    InitChainConfig,
     InitConfig,
     InitPlanPayoutCompDef,
     InitRequest,
     InitResealCompDef,
+    InitTokenConfig,
+    InitTokenRoute,
+    MigrateBondVault,
+    MigrateConfig,
+    MigrateRequest,
+    MigrateRequestEscrow,
     PlanPayoutCallback,
-    QueuePlanPayout,
+    ProposeAdmin,
+    ProposeConfig,
+    RefundRequest,
+    RefundRequestSol,
+    RegisterCircuitVersion,
     ReleaseExpiredClaim,
+    ReleaseFailedClaim,
     ReleaseSol,
     ReleaseSpl,
+    RemoveAttester,
+    RemoveRelayer,
+    RequeuePlanPayout,
+    RequeueReseal,
+    ResealDestinationCallback,
+    RevokeSolver,
+    SetActiveCircuitVersion,
+    SetChainConfig,
     SetConfig,
+    SetPause,
+    SetRelayerThreshold,
+    SetRoles,
+    SetTokenConfig,
+    SetTokenRoute,
+    VerifyAndSettleSol,
     VerifyAndSettleSpl,
+    VoteDelivery,
+    VoteSettlement,
 };
 
 // Aliases the #[arcium_program] macro expects at crate root
+pub(crate) use instructions::admin_accept::__client_accounts_accept_admin;
+pub(crate) use instructions::admin_propose::__client_accounts_propose_admin;
+pub(crate) use instructions::attest_destination::__client_accounts_attest_destination;
+pub(crate) use instructions::attester_add::__client_accounts_add_attester;
+pub(crate) use instructions::attester_remove::__client_accounts_remove_attester;
 pub(crate) use instructions::callback::__client_accounts_plan_payout_callback;
+pub(crate) use instructions::cancel_request::__client_accounts_cancel_request;
+pub(crate) use instructions::cancel_request_sol::__client_accounts_cancel_request_sol;
+pub(crate) use instructions::chain_config_init::__client_accounts_init_chain_config;
+pub(crate) use instructions::chain_config_set::__client_accounts_set_chain_config;
 pub(crate) use instructions::claim_bridge::__client_accounts_claim_request;
+pub(crate) use instructions::close_request::__client_accounts_close_request;
+pub(crate) use instructions::collect_fees::__client_accounts_collect_fees;
+pub(crate) use instructions::config_cancel::__client_accounts_cancel_config;
+pub(crate) use instructions::config_execute::__client_accounts_execute_config;
 pub(crate) use instructions::config_init::__client_accounts_init_config;
+pub(crate) use instructions::config_propose::__client_accounts_propose_config;
 pub(crate) use instructions::config_set::__client_accounts_set_config;
 pub(crate) use instructions::deposit::__client_accounts_deposit_and_queue;
 pub(crate) use instructions::deposit_sol::__client_accounts_deposit_sol_and_queue;
+pub(crate) use instructions::freeze_request::__client_accounts_freeze_request;
 pub(crate) use instructions::init::__client_accounts_init_plan_payout_comp_def;
 pub(crate) use instructions::init_reseal_comp_def::__client_accounts_init_reseal_comp_def;
-pub(crate) use instructions::queue::__client_accounts_queue_plan_payout;
+pub(crate) use instructions::migrate_bond_vault::__client_accounts_migrate_bond_vault;
+pub(crate) use instructions::migrate_config::__client_accounts_migrate_config;
+pub(crate) use instructions::migrate_request::__client_accounts_migrate_request;
+pub(crate) use instructions::migrate_request_escrow::__client_accounts_migrate_request_escrow;
+pub(crate) use instructions::pause_set::__client_accounts_set_pause;
+pub(crate) use instructions::refund::__client_accounts_refund_request;
+pub(crate) use instructions::refund_sol::__client_accounts_refund_request_sol;
+pub(crate) use instructions::register_circuit_version::__client_accounts_register_circuit_version;
+pub(crate) use instructions::relayer_add::__client_accounts_add_relayer;
+pub(crate) use instructions::relayer_remove::__client_accounts_remove_relayer;
+pub(crate) use instructions::relayer_threshold_set::__client_accounts_set_relayer_threshold;
 pub(crate) use instructions::release_expired_claim::__client_accounts_release_expired_claim;
+pub(crate) use instructions::release_failed_claim::__client_accounts_release_failed_claim;
 pub(crate) use instructions::release_sol::__client_accounts_release_sol;
 pub(crate) use instructions::release_spl::__client_accounts_release_spl;
+pub(crate) use instructions::requeue_plan_payout::__client_accounts_requeue_plan_payout;
+pub(crate) use instructions::requeue_reseal::__client_accounts_requeue_reseal;
+pub(crate) use instructions::reseal_callback::__client_accounts_reseal_destination_callback;
+pub(crate) use instructions::roles_set::__client_accounts_set_roles;
+pub(crate) use instructions::set_active_circuit_version::__client_accounts_set_active_circuit_version;
+pub(crate) use instructions::solver_authorize::__client_accounts_authorize_solver;
+pub(crate) use instructions::solver_revoke::__client_accounts_revoke_solver;
+pub(crate) use instructions::token_config_init::__client_accounts_init_token_config;
+pub(crate) use instructions::token_config_set::__client_accounts_set_token_config;
+pub(crate) use instructions::token_route_init::__client_accounts_init_token_route;
+pub(crate) use instructions::token_route_set::__client_accounts_set_token_route;
 pub(crate) use instructions::verify_and_settle::__client_accounts_verify_and_settle_spl;
+pub(crate) use instructions::verify_and_settle_sol::__client_accounts_verify_and_settle_sol;
+pub(crate) use instructions::vote_delivery::__client_accounts_vote_delivery;
+pub(crate) use instructions::vote_settlement::__client_accounts_vote_settlement;
 
 declare_id!("8gk2T4FJYaPUWHDzm5aKccu8HJSpEXYu3rFAoeb7FDE7");
 
@@ -99,41 +225,51 @@ pub mod contracts {
     use super::*;
 
     // ---- Arcium: comp-def init / queue / callback ----
-    pub fn init_plan_payout_comp_def(ctx: Context<InitPlanPayoutCompDef>) -> Result<()> {
+    pub fn init_plan_payout_comp_def(
+        ctx: Context<InitPlanPayoutCompDef>,
+        source: String,
+    ) -> Result<()> {
         trace!("entry:init_plan_payout_comp_def");
         cu("before:init_plan_payout_comp_def");
-        let res = init_plan_payout_comp_def_handler(ctx);
+        let res = init_plan_payout_comp_def_handler(ctx, source);
         cu("after:init_plan_payout_comp_def");
         res
     }
 
-    pub fn init_reseal_comp_def(ctx: Context<InitResealCompDef>) -> Result<()> {
+    pub fn init_reseal_comp_def(ctx: Context<InitResealCompDef>, source: String) -> Result<()> {
         trace!("entry:init_reseal_comp_def");
         cu("before:init_reseal_comp_def");
-        let res = init_reseal_comp_def_handler(ctx);
+        let res = init_reseal_comp_def_handler(ctx, source);
         cu("after:init_reseal_comp_def");
         res
     }
 
-    pub fn queue_plan_payout(
-        ctx: Context<QueuePlanPayout>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn attest_destination(
+        ctx: Context<AttestDestination>,
         computation_offset: u64,
-        pub_key: [u8; 32],
-        nonce: u128,
-        amount_ct: [u8; 32],
-        recipient_tag_ct: [u8; 32],
-    ) -> Result<()> {
-        trace!("entry:queue_plan_payout off={}", computation_offset);
-        cu("before:queue_plan_payout");
-        let res = queue_plan_payout_handler(
+        request_id: u64,
+        client_pubkey: [u8; 32],
+        nonce: [u8; 16],
+        destination_ct0: [u8; 32],
+        destination_ct1: [u8; 32],
+        destination_ct2: [u8; 32],
+        destination_ct3: [u8; 32],
+    ) -> Result<()> {
+        trace!("entry:attest_destination off={}", computation_offset);
+        cu("before:attest_destination");
+        let res = attest_destination_handler(
             ctx,
             computation_offset,
-            pub_key,
+            request_id,
+            client_pubkey,
             nonce,
-            amount_ct,
-            recipient_tag_ct,
+            destination_ct0,
+            destination_ct1,
+            destination_ct2,
+            destination_ct3,
         );
-        cu("after:queue_plan_payout");
+        cu("after:attest_destination");
         res
     }
 
@@ -149,11 +285,74 @@ pub mod contracts {
         res
     }
 
+    #[arcium_callback(encrypted_ix = "reseal_destination")]
+    pub fn reseal_destination_callback(
+        ctx: Context<ResealDestinationCallback>,
+        output: ComputationOutputs<ResealDestinationOutput>,
+    ) -> Result<()> {
+        trace!("entry:reseal_destination_callback");
+        cu("before:reseal_destination_callback");
+        let res = reseal_destination_callback_handler(ctx, output);
+        cu("after:reseal_destination_callback");
+        res
+    }
+
+    pub fn requeue_plan_payout(
+        ctx: Context<RequeuePlanPayout>,
+        request_id: u64,
+        computation_offset: u64,
+    ) -> Result<()> {
+        trace!("entry:requeue_plan_payout");
+        cu("before:requeue_plan_payout");
+        let res = requeue_plan_payout_handler(ctx, request_id, computation_offset);
+        cu("after:requeue_plan_payout");
+        res
+    }
+
+    pub fn requeue_reseal(
+        ctx: Context<RequeueReseal>,
+        request_id: u64,
+        computation_offset: u64,
+    ) -> Result<()> {
+        trace!("entry:requeue_reseal");
+        cu("before:requeue_reseal");
+        let res = requeue_reseal_handler(ctx, request_id, computation_offset);
+        cu("after:requeue_reseal");
+        res
+    }
+
+    // ---- Circuit versions ----
+    pub fn register_circuit_version(
+        ctx: Context<RegisterCircuitVersion>,
+        circuit: u8,
+        version: u32,
+        hash: [u8; 32],
+    ) -> Result<()> {
+        trace!("entry:register_circuit_version");
+        cu("before:register_circuit_version");
+        let res = register_circuit_version_handler(ctx, circuit, version, hash);
+        cu("after:register_circuit_version");
+        res
+    }
+
+    pub fn set_active_circuit_version(
+        ctx: Context<SetActiveCircuitVersion>,
+        circuit: u8,
+        version: u32,
+    ) -> Result<()> {
+        trace!("entry:set_active_circuit_version");
+        cu("before:set_active_circuit_version");
+        let res = set_active_circuit_version_handler(ctx, circuit, version);
+        cu("after:set_active_circuit_version");
+        res
+    }
+
     // ---- Deposits (lock) ----
     pub fn initiate_bridge(
         ctx: Context<DepositAndQueue>,
         computation_offset: u64,
         request_id: u64,
+        dest_chain_id: u64,
         client_pubkey: [u8; 32],
         nonce: [u8; 16],
         destination_ct0: [u8; 32],
@@ -168,6 +367,7 @@ pub mod contracts {
             ctx,
             computation_offset,
             request_id,
+            dest_chain_id,
             client_pubkey,
             nonce,
             destination_ct0,
@@ -184,6 +384,7 @@ pub mod contracts {
         ctx: Context<DepositSolAndQueue>,
         computation_offset: u64,
         request_id: u64,
+        dest_chain_id: u64,
         client_pubkey: [u8; 32],
         nonce: [u8; 16],
         destination_ct0: [u8; 32],
@@ -198,6 +399,7 @@ pub mod contracts {
             ctx,
             computation_offset,
             request_id,
+            dest_chain_id,
             client_pubkey,
             nonce,
             destination_ct0,
@@ -211,18 +413,64 @@ pub mod contracts {
     }
 
     // ---- Releases (unlock) ----
-    pub fn deliver_tokens(ctx: Context<ReleaseSpl>, amount: u64) -> Result<()> {
+    pub fn vote_delivery(
+        ctx: Context<VoteDelivery>,
+        digest: [u8; 32],
+        source_chain_id: u64,
+        source_request_id: [u8; 32],
+        source_tx_hash: [u8; 32],
+        amount: u64,
+    ) -> Result<()> {
+        trace!("entry:vote_delivery chain={}", source_chain_id);
+        cu("before:vote_delivery");
+        let res = vote_delivery_handler(
+            ctx,
+            digest,
+            source_chain_id,
+            source_request_id,
+            source_tx_hash,
+            amount,
+        );
+        cu("after:vote_delivery");
+        res
+    }
+
+    pub fn deliver_tokens(
+        ctx: Context<ReleaseSpl>,
+        source_chain_id: u64,
+        source_request_id: [u8; 32],
+        source_tx_hash: [u8; 32],
+        amount: u64,
+    ) -> Result<()> {
         trace!("entry:deliver_tokens amount={}", amount);
         cu("before:deliver_tokens");
-        let res = release_spl_handler(ctx, amount);
+        let res = release_spl_handler(
+            ctx,
+            source_chain_id,
+            source_request_id,
+            source_tx_hash,
+            amount,
+        );
         cu("after:deliver_tokens");
         res
     }
 
-    pub fn deliver_tokens_sol(ctx: Context<ReleaseSol>, amount: u64) -> Result<()> {
+    pub fn deliver_tokens_sol(
+        ctx: Context<ReleaseSol>,
+        source_chain_id: u64,
+        source_request_id: [u8; 32],
+        source_tx_hash: [u8; 32],
+        amount: u64,
+    ) -> Result<()> {
         trace!("entry:deliver_tokens_sol amount={}", amount);
         cu("before:deliver_tokens_sol");
-        let res = release_sol_handler(ctx, amount);
+        let res = release_sol_handler(
+            ctx,
+            source_chain_id,
+            source_request_id,
+            source_tx_hash,
+            amount,
+        );
         cu("after:deliver_tokens_sol");
         res
     }
@@ -236,6 +484,10 @@ pub mod contracts {
         claim_window_secs: i64,
         min_solver_bond: u64,
         slash_bps: u16,
+        refund_timeout_secs: i64,
+        close_grace_secs: i64,
+        protocol_fee_bps: u16,
+        config_timelock_secs: i64,
     ) -> Result<()> {
         trace!("entry:init_config");
         cu("before:init_config");
@@ -247,6 +499,10 @@ pub mod contracts {
             claim_window_secs,
             min_solver_bond,
             slash_bps,
+            refund_timeout_secs,
+            close_grace_secs,
+            protocol_fee_bps,
+            config_timelock_secs,
         );
         cu("after:init_config");
         res
@@ -257,9 +513,9 @@ pub mod contracts {
         fee_bps: Option<u16>,
         min_fee: Option<u64>,
         max_fee: Option<u64>,
-        claim_window_secs: Option<i64>,
-        min_solver_bond: Option<u64>,
-        slash_bps: Option<u16>,
+        refund_timeout_secs: Option<i64>,
+        close_grace_secs: Option<i64>,
+        max_compute_failures: Option<u8>,
     ) -> Result<()> {
         trace!("entry:set_config");
         cu("before:set_config");
@@ -268,11 +524,273 @@ pub mod contracts {
             fee_bps,
             min_fee,
             max_fee,
+            refund_timeout_secs,
+            close_grace_secs,
+            max_compute_failures,
+        );
+        cu("after:set_config");
+        res
+    }
+
+    // ---- Timelocked config ----
+    pub fn propose_config(
+        ctx: Context<ProposeConfig>,
+        claim_window_secs: Option<i64>,
+        min_solver_bond: Option<u64>,
+        slash_bps: Option<u16>,
+        protocol_fee_bps: Option<u16>,
+        config_timelock_secs: Option<i64>,
+        chain_claim_window_secs: Option<i64>,
+        chain_min_solver_bond: Option<u64>,
+    ) -> Result<()> {
+        trace!("entry:propose_config");
+        cu("before:propose_config");
+        let res = propose_config_handler(
+            ctx,
             claim_window_secs,
             min_solver_bond,
             slash_bps,
+            protocol_fee_bps,
+            config_timelock_secs,
+            chain_claim_window_secs,
+            chain_min_solver_bond,
         );
-        cu("after:set_config");
+        cu("after:propose_config");
+        res
+    }
+
+    pub fn execute_config(ctx: Context<ExecuteConfig>) -> Result<()> {
+        trace!("entry:execute_config");
+        cu("before:execute_config");
+        let res = execute_config_handler(ctx);
+        cu("after:execute_config");
+        res
+    }
+
+    pub fn cancel_config(ctx: Context<CancelConfig>) -> Result<()> {
+        trace!("entry:cancel_config");
+        cu("before:cancel_config");
+        let res = cancel_config_handler(ctx);
+        cu("after:cancel_config");
+        res
+    }
+
+    // ---- Token registry ----
+    pub fn init_token_config(
+        ctx: Context<InitTokenConfig>,
+        enabled: bool,
+        min_deposit: u64,
+        max_deposit: u64,
+        fee_bps: Option<u16>,
+        min_fee: Option<u64>,
+        max_fee: Option<u64>,
+    ) -> Result<()> {
+        trace!("entry:init_token_config");
+        cu("before:init_token_config");
+        let res = init_token_config_handler(
+            ctx,
+            enabled,
+            min_deposit,
+            max_deposit,
+            fee_bps,
+            min_fee,
+            max_fee,
+        );
+        cu("after:init_token_config");
+        res
+    }
+
+    pub fn set_token_config(
+        ctx: Context<SetTokenConfig>,
+        enabled: Option<bool>,
+        min_deposit: Option<u64>,
+        max_deposit: Option<u64>,
+        fee_bps: Option<u16>,
+        min_fee: Option<u64>,
+        max_fee: Option<u64>,
+    ) -> Result<()> {
+        trace!("entry:set_token_config");
+        cu("before:set_token_config");
+        let res = set_token_config_handler(
+            ctx,
+            enabled,
+            min_deposit,
+            max_deposit,
+            fee_bps,
+            min_fee,
+            max_fee,
+        );
+        cu("after:set_token_config");
+        res
+    }
+
+    pub fn init_token_route(
+        ctx: Context<InitTokenRoute>,
+        dest_chain_id: u64,
+        dest_token: [u8; 20],
+        dest_decimals: u8,
+        enabled: bool,
+    ) -> Result<()> {
+        trace!("entry:init_token_route chain={}", dest_chain_id);
+        cu("before:init_token_route");
+        let res = init_token_route_handler(ctx, dest_chain_id, dest_token, dest_decimals, enabled);
+        cu("after:init_token_route");
+        res
+    }
+
+    pub fn set_token_route(
+        ctx: Context<SetTokenRoute>,
+        dest_token: Option<[u8; 20]>,
+        dest_decimals: Option<u8>,
+        enabled: Option<bool>,
+    ) -> Result<()> {
+        trace!("entry:set_token_route");
+        cu("before:set_token_route");
+        let res = set_token_route_handler(ctx, dest_token, dest_decimals, enabled);
+        cu("after:set_token_route");
+        res
+    }
+
+    // ---- Chain registry ----
+    pub fn init_chain_config(
+        ctx: Context<InitChainConfig>,
+        chain_id: u64,
+        enabled: bool,
+        claim_window_secs: i64,
+        min_solver_bond: u64,
+        dest_gas_fee_lamports: u64,
+    ) -> Result<()> {
+        trace!("entry:init_chain_config chain={}", chain_id);
+        cu("before:init_chain_config");
+        let res = init_chain_config_handler(
+            ctx,
+            chain_id,
+            enabled,
+            claim_window_secs,
+            min_solver_bond,
+            dest_gas_fee_lamports,
+        );
+        cu("after:init_chain_config");
+        res
+    }
+
+    pub fn set_chain_config(
+        ctx: Context<SetChainConfig>,
+        enabled: Option<bool>,
+        dest_gas_fee_lamports: Option<u64>,
+    ) -> Result<()> {
+        trace!("entry:set_chain_config");
+        cu("before:set_chain_config");
+        let res = set_chain_config_handler(ctx, enabled, dest_gas_fee_lamports);
+        cu("after:set_chain_config");
+        res
+    }
+
+    // ---- Roles ----
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        trace!("entry:propose_admin");
+        cu("before:propose_admin");
+        let res = propose_admin_handler(ctx, new_admin);
+        cu("after:propose_admin");
+        res
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        trace!("entry:accept_admin");
+        cu("before:accept_admin");
+        let res = accept_admin_handler(ctx);
+        cu("after:accept_admin");
+        res
+    }
+
+    pub fn set_roles(
+        ctx: Context<SetRoles>,
+        fee_collector: Option<Pubkey>,
+        slash_collector: Option<Pubkey>,
+        pauser: Option<Pubkey>,
+    ) -> Result<()> {
+        trace!("entry:set_roles");
+        cu("before:set_roles");
+        let res = set_roles_handler(ctx, fee_collector, slash_collector, pauser);
+        cu("after:set_roles");
+        res
+    }
+
+    pub fn add_relayer(ctx: Context<AddRelayer>, relayer: Pubkey) -> Result<()> {
+        trace!("entry:add_relayer");
+        cu("before:add_relayer");
+        let res = add_relayer_handler(ctx, relayer);
+        cu("after:add_relayer");
+        res
+    }
+
+    pub fn remove_relayer(ctx: Context<RemoveRelayer>, relayer: Pubkey) -> Result<()> {
+        trace!("entry:remove_relayer");
+        cu("before:remove_relayer");
+        let res = remove_relayer_handler(ctx, relayer);
+        cu("after:remove_relayer");
+        res
+    }
+
+    pub fn set_relayer_threshold(ctx: Context<SetRelayerThreshold>, threshold: u8) -> Result<()> {
+        trace!("entry:set_relayer_threshold");
+        cu("before:set_relayer_threshold");
+        let res = set_relayer_threshold_handler(ctx, threshold);
+        cu("after:set_relayer_threshold");
+        res
+    }
+
+    pub fn add_attester(
+        ctx: Context<AddAttester>,
+        scheme: SignatureScheme,
+        key: [u8; 32],
+    ) -> Result<()> {
+        trace!("entry:add_attester");
+        cu("before:add_attester");
+        let res = add_attester_handler(ctx, scheme, key);
+        cu("after:add_attester");
+        res
+    }
+
+    pub fn remove_attester(
+        ctx: Context<RemoveAttester>,
+        scheme: SignatureScheme,
+        key: [u8; 32],
+    ) -> Result<()> {
+        trace!("entry:remove_attester");
+        cu("before:remove_attester");
+        let res = remove_attester_handler(ctx, scheme, key);
+        cu("after:remove_attester");
+        res
+    }
+
+    // ---- Solver registry ----
+    pub fn authorize_solver(
+        ctx: Context<AuthorizeSolver>,
+        solver: Pubkey,
+        x25519: [u8; 32],
+    ) -> Result<()> {
+        trace!("entry:authorize_solver");
+        cu("before:authorize_solver");
+        let res = authorize_solver_handler(ctx, solver, x25519);
+        cu("after:authorize_solver");
+        res
+    }
+
+    pub fn revoke_solver(ctx: Context<RevokeSolver>) -> Result<()> {
+        trace!("entry:revoke_solver");
+        cu("before:revoke_solver");
+        let res = revoke_solver_handler(ctx);
+        cu("after:revoke_solver");
+        res
+    }
+
+    // ---- Emergency pause ----
+    pub fn set_pause(ctx: Context<SetPause>, paused: u8) -> Result<()> {
+        trace!("entry:set_pause paused={}", paused);
+        cu("before:set_pause");
+        let res = set_pause_handler(ctx, paused);
+        cu("after:set_pause");
         res
     }
 
@@ -298,12 +816,46 @@ pub mod contracts {
         res
     }
 
+    pub fn migrate_bond_vault(ctx: Context<MigrateBondVault>, request_id: u64) -> Result<()> {
+        trace!("entry:migrate_bond_vault req_id={}", request_id);
+        cu("before:migrate_bond_vault");
+        let res = migrate_bond_vault_handler(ctx, request_id);
+        cu("after:migrate_bond_vault");
+        res
+    }
+
+    pub fn vote_settlement(
+        ctx: Context<VoteSettlement>,
+        request_id: u64,
+        digest: [u8; 32],
+        dest_tx_hash: [u8; 32],
+        evidence_hash: [u8; 32],
+        dest_token: [u8; 20],
+        dest_amount: u128,
+    ) -> Result<()> {
+        trace!("entry:vote_settlement req_id={}", request_id);
+        cu("before:vote_settlement");
+        let res = vote_settlement_handler(
+            ctx,
+            request_id,
+            digest,
+            dest_tx_hash,
+            evidence_hash,
+            dest_token,
+            dest_amount,
+        );
+        cu("after:vote_settlement");
+        res
+    }
+
     pub fn verify_and_settle_spl(
         ctx: Context<VerifyAndSettleSpl>,
         request_id: u64,
         dest_tx_hash: [u8; 32],
         evidence_hash: [u8; 32],
         evidence_url: String,
+        dest_token: [u8; 20],
+        dest_amount: u128,
     ) -> Result<()> {
         trace!("entry:verify_and_settle_spl req_id={}", request_id);
         cu("before:verify_and_settle_spl");
@@ -313,8 +865,140 @@ pub mod contracts {
             dest_tx_hash,
             evidence_hash,
             evidence_url,
+            dest_token,
+            dest_amount,
         );
         cu("after:verify_and_settle_spl");
         res
     }
+
+    pub fn verify_and_settle_sol(
+        ctx: Context<VerifyAndSettleSol>,
+        request_id: u64,
+        dest_tx_hash: [u8; 32],
+        evidence_hash: [u8; 32],
+        evidence_url: String,
+        dest_token: [u8; 20],
+        dest_amount: u128,
+    ) -> Result<()> {
+        trace!("entry:verify_and_settle_sol req_id={}", request_id);
+        cu("before:verify_and_settle_sol");
+        let res = verify_and_settle_sol_handler(
+            ctx,
+            request_id,
+            dest_tx_hash,
+            evidence_hash,
+            evidence_url,
+            dest_token,
+            dest_amount,
+        );
+        cu("after:verify_and_settle_sol");
+        res
+    }
+
+    pub fn release_failed_claim(ctx: Context<ReleaseFailedClaim>, request_id: u64) -> Result<()> {
+        trace!("entry:release_failed_claim");
+        cu("before:release_failed_claim");
+        let res = release_failed_claim_handler(ctx, request_id);
+        cu("after:release_failed_claim");
+        res
+    }
+
+    // ---- Refunds (unclaimed requests) ----
+    pub fn refund_request(ctx: Context<RefundRequest>, request_id: u64) -> Result<()> {
+        trace!("entry:refund_request req_id={}", request_id);
+        cu("before:refund_request");
+        let res = refund_request_handler(ctx, request_id);
+        cu("after:refund_request");
+        res
+    }
+
+    pub fn refund_request_sol(ctx: Context<RefundRequestSol>, request_id: u64) -> Result<()> {
+        trace!("entry:refund_request_sol req_id={}", request_id);
+        cu("before:refund_request_sol");
+        let res = refund_request_sol_handler(ctx, request_id);
+        cu("after:refund_request_sol");
+        res
+    }
+
+    // ---- Incidents (pauser freezes, admin cancels) ----
+    pub fn freeze_request(ctx: Context<FreezeRequest>, request_id: u64) -> Result<()> {
+        trace!("entry:freeze_request req_id={}", request_id);
+        cu("before:freeze_request");
+        let res = freeze_request_handler(ctx, request_id);
+        cu("after:freeze_request");
+        res
+    }
+
+    pub fn cancel_request(ctx: Context<CancelRequest>, request_id: u64) -> Result<()> {
+        trace!("entry:cancel_request req_id={}", request_id);
+        cu("before:cancel_request");
+        let res = cancel_request_handler(ctx, request_id);
+        cu("after:cancel_request");
+        res
+    }
+
+    pub fn cancel_request_sol(ctx: Context<CancelRequestSol>, request_id: u64) -> Result<()> {
+        trace!("entry:cancel_request_sol req_id={}", request_id);
+        cu("before:cancel_request_sol");
+        let res = cancel_request_sol_handler(ctx, request_id);
+        cu("after:cancel_request_sol");
+        res
+    }
+
+    // ---- Cleanup (rent) ----
+    pub fn close_request(ctx: Context<CloseRequest>, request_id: u64) -> Result<()> {
+        trace!("entry:close_request req_id={}", request_id);
+        cu("before:close_request");
+        let res = close_request_handler(ctx, request_id);
+        cu("after:close_request");
+        res
+    }
+
+    // ---- Protocol fees ----
+    pub fn collect_fees(ctx: Context<CollectFees>) -> Result<()> {
+        trace!("entry:collect_fees");
+        cu("before:collect_fees");
+        let res = collect_fees_handler(ctx);
+        cu("after:collect_fees");
+        res
+    }
+
+    // ---- Layout migrations ----
+    pub fn migrate_config(
+        ctx: Context<MigrateConfig>,
+        refund_timeout_secs: i64,
+        close_grace_secs: i64,
+        config_timelock_secs: i64,
+    ) -> Result<()> {
+        trace!("entry:migrate_config");
+        cu("before:migrate_config");
+        let res = migrate_config_handler(
+            ctx,
+            refund_timeout_secs,
+            close_grace_secs,
+            config_timelock_secs,
+        );
+        cu("after:migrate_config");
+        res
+    }
+
+    pub fn migrate_request(ctx: Context<MigrateRequest>) -> Result<()> {
+        trace!("entry:migrate_request");
+        cu("before:migrate_request");
+        let res = migrate_request_handler(ctx);
+        cu("after:migrate_request");
+        res
+    }
+
+    pub fn migrate_request_escrow(
+        ctx: Context<MigrateRequestEscrow>,
+        request_id: u64,
+    ) -> Result<()> {
+        trace!("entry:migrate_request_escrow req_id={}", request_id);
+        cu("before:migrate_request_escrow");
+        let res = migrate_request_escrow_handler(ctx, request_id);
+        cu("after:migrate_request_escrow");
+        res
+    }
 }
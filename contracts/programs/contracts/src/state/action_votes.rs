@@ -0,0 +1,122 @@
+use crate::errors::ErrorCode;
+use crate::state::{BridgeConfig, MAX_RELAYERS};
+use anchor_lang::prelude::*;
+
+// `ActionVotes::kind`
+pub const VOTE_KIND_SETTLEMENT: u8 = 0;
+pub const VOTE_KIND_DELIVERY: u8 = 1;
+
+/// How long a voting round stays open after its first vote
+pub const VOTE_TTL_SECS: i64 = 24 * 60 * 60;
+
+/// Relayer approvals of one action. PDA: [b"votes", digest] (see `crate::digest`)
+/// The first vote snapshots the threshold; a round needs the higher of it and the live
+/// quorum, and only votes of relayers still in `config.relayers` count, so removing a
+/// relayer also withdraws its votes. A round that expires restarts on the next vote; the
+/// action closes the account when it executes.
+#[account]
+pub struct ActionVotes {
+    pub digest: [u8; 32],
+    pub kind: u8, // VOTE_KIND_*
+    pub threshold: u8,
+    pub voters: [Pubkey; MAX_RELAYERS], // first `vote_count` entries are live
+    pub vote_count: u8,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl ActionVotes {
+    pub fn has_voted(&self, key: &Pubkey) -> bool {
+        self.voters[..self.vote_count as usize].contains(key)
+    }
+
+    /// Votes of relayers that are still in the set.
+    pub fn live_votes(&self, cfg: &BridgeConfig) -> usize {
+        self.voters[..self.vote_count as usize]
+            .iter()
+            .filter(|v| cfg.is_relayer(v))
+            .count()
+    }
+
+    /// Approvals the round needs: its snapshot, raised if the quorum went up since.
+    pub fn required(&self, cfg: &BridgeConfig) -> u8 {
+        self.threshold.max(cfg.quorum())
+    }
+
+    /// Records `relayer`'s vote, opening a new round if there is none or it expired.
+    pub fn cast(
+        &mut self,
+        cfg: &BridgeConfig,
+        digest: [u8; 32],
+        kind: u8,
+        relayer: Pubkey,
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        if self.vote_count == 0 || now > self.expires_at {
+            self.digest = digest;
+            self.kind = kind;
+            self.threshold = cfg.quorum();
+            self.voters = [Pubkey::default(); MAX_RELAYERS];
+            self.vote_count = 0;
+            self.expires_at = now + VOTE_TTL_SECS;
+            self.bump = bump;
+        }
+
+        // Drop votes of removed relayers so they neither count nor hold a slot
+        let mut live = 0;
+        for i in 0..self.vote_count as usize {
+            if cfg.is_relayer(&self.voters[i]) {
+                self.voters[live] = self.voters[i];
+                live += 1;
+            }
+        }
+        self.voters[live..].fill(Pubkey::default());
+        self.vote_count = live as u8;
+
+        require!(!self.has_voted(&relayer), ErrorCode::AlreadyVoted);
+        require!(
+            (self.vote_count as usize) < MAX_RELAYERS,
+            ErrorCode::TooManyRelayers
+        );
+
+        self.voters[self.vote_count as usize] = relayer;
+        self.vote_count += 1;
+        Ok(())
+    }
+}
+
+/// Gate of every quorum-protected action. `executor` is a live relayer and counts as one
+/// more approval, as does each of the `attestations` (see `crate::attestation`); with a
+/// threshold of 1 no votes account is needed at all.
+pub fn require_quorum(
+    cfg: &BridgeConfig,
+    votes: Option<&ActionVotes>,
+    digest: &[u8; 32],
+    kind: u8,
+    executor: &Pubkey,
+    attestations: u8,
+    now: i64,
+) -> Result<()> {
+    let Some(votes) = votes else {
+        require!(
+            1 + attestations as usize >= cfg.quorum() as usize,
+            ErrorCode::QuorumNotReached
+        );
+        return Ok(());
+    };
+
+    require!(
+        votes.digest == *digest && votes.kind == kind,
+        ErrorCode::VoteDigestMismatch
+    );
+    require!(now <= votes.expires_at, ErrorCode::VotesExpired);
+
+    let approvals =
+        votes.live_votes(cfg) + usize::from(!votes.has_voted(executor)) + attestations as usize;
+    require!(
+        approvals >= votes.required(cfg) as usize,
+        ErrorCode::QuorumNotReached
+    );
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::errors::ErrorCode;
+use anchor_lang::prelude::*;
+
+/// Max keys per scheme in `AttesterSet`
+pub const MAX_ATTESTERS: usize = 8;
+
+/// Signature scheme of an attester key.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SignatureScheme {
+    /// EVM key, identified by its 20-byte address
+    Secp256k1,
+    Ed25519,
+}
+
+/// Off-chain verifiers whose signed digests count as relayer approvals. PDA: [b"attesters"]
+/// Signatures are checked when the action executes, so a removed key stops counting at once.
+#[account]
+pub struct AttesterSet {
+    pub evm: [[u8; 20]; MAX_ATTESTERS], // first `evm_count` entries are live
+    pub evm_count: u8,
+    pub ed25519: [Pubkey; MAX_ATTESTERS], // first `ed25519_count` entries are live
+    pub ed25519_count: u8,
+    pub bump: u8,
+}
+
+impl AttesterSet {
+    /// EVM address of an attester key: a 20-byte address right-aligned in 32 bytes
+    /// (ABI encoding), the leading 12 bytes zero.
+    pub fn evm_address(key: &[u8; 32]) -> Result<[u8; 20]> {
+        require!(key[..12] == [0u8; 12], ErrorCode::InvalidAttesterKey);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&key[12..]);
+        Ok(address)
+    }
+
+    pub fn evm_index(&self, address: &[u8]) -> Option<usize> {
+        self.evm[..self.evm_count as usize]
+            .iter()
+            .position(|a| a.as_slice() == address)
+    }
+
+    pub fn ed25519_index(&self, key: &[u8]) -> Option<usize> {
+        self.ed25519[..self.ed25519_count as usize]
+            .iter()
+            .position(|k| k.as_ref() == key)
+    }
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// Per destination chain parameters. PDA: [b"chain", chain_id (LE)]
+#[account]
+pub struct ChainConfig {
+    pub chain_id: u64,
+    pub enabled: bool, // new deposits only; existing requests can still settle
+    pub claim_window_secs: i64, // timelocked; sized to the chain's finality; 0 = global default
+    pub min_solver_bond: u64, // timelocked; lamports; the claim uses max(global, chain)
+    pub dest_gas_fee_lamports: u64, // charged at deposit, paid to the solver at settle
+    pub bump: u8,
+}
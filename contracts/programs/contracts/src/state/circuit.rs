@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::comp_def_offset;
+
+/// Circuit ids used in the registry seeds
+pub const CIRCUIT_PLAN_PAYOUT: u8 = 0;
+pub const CIRCUIT_RESEAL: u8 = 1;
+
+/// `encrypted-ixs` name of version 1 of a circuit.
+pub fn circuit_name(circuit: u8) -> Option<&'static str> {
+    match circuit {
+        CIRCUIT_PLAN_PAYOUT => Some("plan_payout"),
+        CIRCUIT_RESEAL => Some("reseal_destination"),
+        _ => None,
+    }
+}
+
+/// Comp-def offset of a version of a circuit. Arcium derives the offset from the circuit
+/// name, so version 1 is `<name>` and version N is `<name>_v<N>`; no other circuit's
+/// comp-def can be registered as a version of this one.
+pub fn version_comp_def_offset(circuit: u8, version: u32) -> Option<u32> {
+    let name = circuit_name(circuit)?;
+    match version {
+        0 => None,
+        1 => Some(comp_def_offset(name)),
+        v => Some(comp_def_offset(&format!("{name}_v{v}"))),
+    }
+}
+
+/// Active version of one circuit. PDA: [b"circuit", &[circuit]]
+/// Queue paths submit to the active version's comp-def and pass its `CircuitVersion` to the
+/// callback, which accepts any registered version of its circuit. Arcium ties a comp-def to
+/// its circuit name, so a new version is a new circuit `<name>_v<N>` in `encrypted-ixs` with
+/// the same inputs and outputs, initialized through its own comp-def init and then recorded
+/// with `register_circuit_version`.
+#[account]
+pub struct CircuitRegistry {
+    pub circuit: u8,
+    pub active_version: u32,
+    pub active_offset: u32,
+    pub latest_version: u32, // versions are registered sequentially
+    pub bump: u8,
+}
+
+/// One registered version of a circuit. PDA: [b"circuit_version", &[circuit], version_le]
+#[account]
+pub struct CircuitVersion {
+    pub circuit: u8,
+    pub version: u32,
+    pub comp_def_offset: u32,
+    pub hash: [u8; 32], // sha256 of the .arcis the MXE must fetch
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{COMP_DEF_OFFSET_PLAN_PAYOUT, COMP_DEF_OFFSET_RESEAL};
+
+    #[test]
+    fn version_one_is_the_initial_comp_def() {
+        assert_eq!(
+            version_comp_def_offset(CIRCUIT_PLAN_PAYOUT, 1),
+            Some(COMP_DEF_OFFSET_PLAN_PAYOUT)
+        );
+        assert_eq!(
+            version_comp_def_offset(CIRCUIT_RESEAL, 1),
+            Some(COMP_DEF_OFFSET_RESEAL)
+        );
+    }
+
+    #[test]
+    fn later_versions_use_their_own_circuit_name() {
+        assert_eq!(
+            version_comp_def_offset(CIRCUIT_PLAN_PAYOUT, 2),
+            Some(comp_def_offset("plan_payout_v2"))
+        );
+        assert_eq!(version_comp_def_offset(CIRCUIT_PLAN_PAYOUT, 0), None);
+        assert_eq!(version_comp_def_offset(2, 1), None);
+    }
+}
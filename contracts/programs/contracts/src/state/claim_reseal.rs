@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+/// Outcome of the `reseal_destination` computation queued by a claim.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ResealStatus {
+    Queued,
+    Delivered,
+    /// The MPC aborted; the solver can release the claim without being slashed
+    Failed,
+}
+
+/// Destination resealed to the current solver. PDA: [b"reseal", request_pda]
+/// Reset by every claim; the solver decrypts `ct_w0..w3` with its x25519 key and `nonce`.
+#[account]
+pub struct ClaimReseal {
+    pub request: Pubkey,
+    pub solver: Pubkey,
+    pub solver_x25519: [u8; 32], // key the claim resealed to; outputs for another key are stale
+    pub status: ResealStatus,
+    pub nonce: u128,
+    pub ct_w0: [u8; 32],
+    pub ct_w1: [u8; 32],
+    pub ct_w2: [u8; 32],
+    pub ct_w3: [u8; 32],
+    pub resealed_at: i64,
+    pub bump: u8,
+    pub rent_payer: Pubkey, // solver whose claim created this account; gets the rent on close
+}
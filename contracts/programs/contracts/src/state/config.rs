@@ -1,13 +1,73 @@
 use anchor_lang::prelude::*;
+use core::mem::size_of;
+
+/// Current `BridgeConfig` layout. 0 = pre-versioning layout (see `state::legacy`).
+pub const CONFIG_VERSION: u8 = 1;
+
+/// Max number of keys in `BridgeConfig::relayers`
+pub const MAX_RELAYERS: usize = 8;
+
+// Bits of `BridgeConfig::paused`. Refunds are never gated so users can always exit.
+pub const PAUSE_DEPOSITS: u8 = 1 << 0; // initiate_bridge / initiate_bridge_sol
+pub const PAUSE_CLAIMS: u8 = 1 << 1; // claim_bridge
+pub const PAUSE_SETTLEMENTS: u8 = 1 << 2; // verify_and_settle_*
+pub const PAUSE_DELIVERIES: u8 = 1 << 3; // deliver_tokens / deliver_tokens_sol
+pub const PAUSE_ALL: u8 = PAUSE_DEPOSITS | PAUSE_CLAIMS | PAUSE_SETTLEMENTS | PAUSE_DELIVERIES;
+
+/// `max_compute_failures` set by init_config / migrate_config
+pub const DEFAULT_MAX_COMPUTE_FAILURES: u8 = 3;
 
 #[account]
 pub struct BridgeConfig {
-    pub owner: Pubkey,
-    pub fee_bps: u16,           // 0..=1000 (10%)
-    pub min_fee: u64,           // in token units
-    pub max_fee: u64,           // in token units
-    pub claim_window_secs: i64, // e.g. 600 (10 min)
-    pub min_solver_bond: u64,   // lamports
-    pub slash_bps: u16,         // 0..=10000
+    pub version: u8, // layout version, see CONFIG_VERSION
+    pub admin: Pubkey,
+    pub fee_bps: u16,              // default for new TokenConfigs
+    pub min_fee: u64,              // default for new TokenConfigs
+    pub max_fee: u64,              // default for new TokenConfigs
+    pub claim_window_secs: i64,    // timelocked; fallback when a chain sets 0
+    pub min_solver_bond: u64,      // timelocked; lamports
+    pub slash_bps: u16,            // timelocked; 0..=10000
+    pub refund_timeout_secs: i64,  // unclaimed requests refundable after this (snapshotted)
+    pub close_grace_secs: i64,     // finished requests closable after this
+    pub protocol_fee_bps: u16,     // timelocked; protocol share of fee_locked, 0..=10000
+    pub config_timelock_secs: i64, // delay for propose_config -> execute_config
+
+    // --- roles ---
+    pub pending_admin: Pubkey,            // proposed admin; default = none
+    pub relayers: [Pubkey; MAX_RELAYERS], // first `relayer_count` entries are live
+    pub relayer_count: u8,
+    pub fee_collector: Pubkey,   // may call collect_fees
+    pub slash_collector: Pubkey, // receives the slashed share of bonds
+    pub pauser: Pubkey,
+    pub paused: u8, // PAUSE_* bitmask
+
     pub bump: u8,
+
+    pub max_compute_failures: u8, // aborted computations before an immediate refund; 0 = never
+    pub relayer_threshold: u8,    // relayer approvals to settle / deliver; 0 is read as 1
+
+    /// Room for new fields without a realloc; must stay zeroed until used
+    pub reserved: [u8; 62],
+}
+
+impl BridgeConfig {
+    /// Account size (discriminator included) of the current layout.
+    pub const LEN: usize = 8 + size_of::<BridgeConfig>();
+
+    pub fn active_relayers(&self) -> &[Pubkey] {
+        &self.relayers[..self.relayer_count as usize]
+    }
+
+    pub fn is_relayer(&self, key: &Pubkey) -> bool {
+        self.active_relayers().contains(key)
+    }
+
+    /// Approvals a settlement or delivery needs. Configs predating the field read 0.
+    pub fn quorum(&self) -> u8 {
+        self.relayer_threshold.max(1)
+    }
+
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.paused & flag != 0
+    }
 }
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Record of an inbound (EVM -> Solana) payout.
+/// PDA: [b"delivery", source_chain_id_le, source_request_id]; created with `init`, so a
+/// source request can only ever be delivered once.
+#[account]
+pub struct DeliveryReceipt {
+    pub source_chain_id: u64,
+    pub source_request_id: [u8; 32], // uint256 on the source chain, big-endian
+    pub source_tx_hash: [u8; 32],
+    pub recipient: Pubkey,
+    pub token: Pubkey, // SPL mint; WSOL mint for native SOL
+    pub amount: u64,
+    pub relayer: Pubkey,
+    pub delivered_at: i64,
+    pub bump: u8,
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// Protocol treasury for one mint. PDA: [b"fee_vault", mint]
+/// The tokens sit in the ATA owned by this PDA; `collected_fees` is the uncollected
+/// balance (EVM `collectedFees[token]`).
+#[account]
+pub struct FeeVault {
+    pub mint: Pubkey,
+    pub collected_fees: u64,
+    pub bump: u8,
+}
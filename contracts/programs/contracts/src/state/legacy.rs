@@ -0,0 +1,279 @@
+//! Pre-versioning (V0) account layouts, as deployed before `version` existed.
+//! V0 accounts carry the same discriminators as today's and are recognised by their size
+//! (`8 + size_of::<V0>()`, how they were allocated). Only used by the migrate instructions.
+
+use anchor_lang::prelude::*;
+use core::mem::size_of;
+
+use crate::state::{
+    BridgeConfig, BridgeRequest, RequestStatus, CONFIG_VERSION, DEFAULT_MAX_COMPUTE_FAILURES,
+    MAX_RELAYERS, REQUEST_VERSION,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct BridgeConfigV0 {
+    pub owner: Pubkey,
+    pub fee_bps: u16,
+    pub min_fee: u64,
+    pub max_fee: u64,
+    pub claim_window_secs: i64,
+    pub min_solver_bond: u64,
+    pub slash_bps: u16,
+    pub bump: u8,
+}
+
+impl BridgeConfigV0 {
+    pub const LEN: usize = 8 + size_of::<BridgeConfigV0>();
+
+    /// Current layout; every role goes to the V0 owner and protocol fees start at 0
+    /// (settlements keep paying the whole fee to the solver until changed).
+    pub fn upgrade(
+        &self,
+        refund_timeout_secs: i64,
+        close_grace_secs: i64,
+        config_timelock_secs: i64,
+    ) -> BridgeConfig {
+        let mut relayers = [Pubkey::default(); MAX_RELAYERS];
+        relayers[0] = self.owner;
+        BridgeConfig {
+            version: CONFIG_VERSION,
+            admin: self.owner,
+            fee_bps: self.fee_bps,
+            min_fee: self.min_fee,
+            max_fee: self.max_fee,
+            claim_window_secs: self.claim_window_secs,
+            min_solver_bond: self.min_solver_bond,
+            slash_bps: self.slash_bps,
+            refund_timeout_secs,
+            close_grace_secs,
+            protocol_fee_bps: 0,
+            config_timelock_secs,
+            pending_admin: Pubkey::default(),
+            relayers,
+            relayer_count: 1,
+            fee_collector: self.owner,
+            slash_collector: self.owner,
+            pauser: self.owner,
+            paused: 0,
+            bump: self.bump,
+            max_compute_failures: DEFAULT_MAX_COMPUTE_FAILURES,
+            relayer_threshold: 1,
+            reserved: [0u8; 62],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct BridgeRequestV0 {
+    pub request_id: u64,
+    pub payer: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount_locked: u64,
+    pub fee_locked: u64,
+    pub created_at: i64,
+    pub claimed: bool,
+    pub solver: Pubkey,
+    pub claim_deadline: i64,
+    pub bond_lamports: u64,
+    pub finalized: bool,
+    pub bump: u8,
+    pub client_pubkey: [u8; 32],
+    pub nonce_le: u128,
+    pub dest_ct_w0: [u8; 32],
+    pub dest_ct_w1: [u8; 32],
+    pub dest_ct_w2: [u8; 32],
+    pub dest_ct_w3: [u8; 32],
+}
+
+impl BridgeRequestV0 {
+    pub const LEN: usize = 8 + size_of::<BridgeRequestV0>();
+
+    /// Current layout. V0 had no refunds, so `finalized` means Settled. The bond (if any)
+    /// is still in the request_id-keyed vault (`bond_bump = 0`) and the funds of an open
+    /// request are still in the shared sign-PDA escrow (`legacy_escrow`).
+    /// V0 never recorded the `plan_payout` result, so an unclaimed request comes out as
+    /// `AttestationFailed`: it is requeued (stored ciphertext) or refunded.
+    /// There is no destination data; `migrate_request` fills it in from a token route for
+    /// open requests and refuses claimed ones.
+    /// `claim_window_secs` / `slash_bps` / `refund_timeout_secs` are the config terms live at
+    /// migration time.
+    pub fn upgrade(
+        &self,
+        claim_window_secs: i64,
+        slash_bps: u16,
+        refund_timeout_secs: i64,
+        now: i64,
+    ) -> BridgeRequest {
+        let status = if self.finalized {
+            RequestStatus::Settled
+        } else if self.claimed {
+            RequestStatus::Claimed
+        } else {
+            RequestStatus::AttestationFailed
+        };
+        BridgeRequest {
+            status,
+            version: REQUEST_VERSION,
+            request_id: self.request_id,
+            payer: self.payer,
+            token_mint: self.token_mint,
+            amount_locked: self.amount_locked,
+            fee_locked: self.fee_locked,
+            token_config_version: 0,
+            created_at: self.created_at,
+            solver: self.solver,
+            claim_deadline: self.claim_deadline,
+            bond_lamports: self.bond_lamports,
+            bump: self.bump,
+            bond_bump: 0,
+            finalized_at: if self.finalized { now } else { 0 },
+            dest_chain_id: 0,
+            dest_token: [0u8; 20],
+            expected_dest_amount: 0,
+            dest_gas_fee_lamports: 0,
+            claim_window_secs,
+            refund_timeout_secs,
+            slash_bps,
+            protocol_fee_bps: 0,
+            client_pubkey: self.client_pubkey,
+            nonce_le: self.nonce_le,
+            dest_ct_w0: self.dest_ct_w0,
+            dest_ct_w1: self.dest_ct_w1,
+            dest_ct_w2: self.dest_ct_w2,
+            dest_ct_w3: self.dest_ct_w3,
+            attested_nonce: 0,
+            compute_failures: 0,
+            legacy_escrow: !self.finalized,
+            bond_rent_payer: Pubkey::default(),
+            ever_claimed: false,
+            reserved: [0u8; 5],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Fixtures are laid out by hand, field by field, exactly as the V0 program wrote them,
+    // so a change to the V0 structs above that breaks decoding is caught here.
+
+    fn config_v0_fixture() -> Vec<u8> {
+        let mut d = BridgeConfig::DISCRIMINATOR.to_vec();
+        d.extend_from_slice(&[7u8; 32]); // owner
+        d.extend_from_slice(&30u16.to_le_bytes()); // fee_bps
+        d.extend_from_slice(&1_000u64.to_le_bytes()); // min_fee
+        d.extend_from_slice(&100_000_000u64.to_le_bytes()); // max_fee
+        d.extend_from_slice(&1_200i64.to_le_bytes()); // claim_window_secs
+        d.extend_from_slice(&20_000_000u64.to_le_bytes()); // min_solver_bond
+        d.extend_from_slice(&5_000u16.to_le_bytes()); // slash_bps
+        d.push(254); // bump
+        d.resize(BridgeConfigV0::LEN, 0); // allocated with size_of, tail is zero
+        d
+    }
+
+    fn request_v0_fixture(claimed: bool, finalized: bool) -> Vec<u8> {
+        let mut d = BridgeRequest::DISCRIMINATOR.to_vec();
+        d.extend_from_slice(&42u64.to_le_bytes()); // request_id
+        d.extend_from_slice(&[1u8; 32]); // payer
+        d.extend_from_slice(&[2u8; 32]); // token_mint
+        d.extend_from_slice(&2_997_000u64.to_le_bytes()); // amount_locked
+        d.extend_from_slice(&3_000u64.to_le_bytes()); // fee_locked
+        d.extend_from_slice(&1_700_000_000i64.to_le_bytes()); // created_at
+        d.push(claimed as u8);
+        d.extend_from_slice(&[3u8; 32]); // solver
+        d.extend_from_slice(&1_700_001_200i64.to_le_bytes()); // claim_deadline
+        d.extend_from_slice(&20_000_000u64.to_le_bytes()); // bond_lamports
+        d.push(finalized as u8);
+        d.push(253); // bump
+        d.extend_from_slice(&[4u8; 32]); // client_pubkey
+        d.extend_from_slice(&0x0102_0304u128.to_le_bytes()); // nonce_le
+        for w in 5u8..9 {
+            d.extend_from_slice(&[w; 32]); // dest_ct_w0..w3
+        }
+        d.resize(BridgeRequestV0::LEN, 0);
+        d
+    }
+
+    #[test]
+    fn v0_accounts_are_smaller_than_current() {
+        assert!(BridgeConfigV0::LEN < BridgeConfig::LEN);
+        assert!(BridgeRequestV0::LEN < BridgeRequest::LEN);
+    }
+
+    #[test]
+    fn config_v0_fixture_upgrades() {
+        let data = config_v0_fixture();
+        let v0 = BridgeConfigV0::deserialize(&mut &data[8..]).unwrap();
+        assert_eq!(v0.owner, Pubkey::new_from_array([7u8; 32]));
+        assert_eq!(v0.slash_bps, 5_000);
+        assert_eq!(v0.bump, 254);
+
+        let cfg = v0.upgrade(86_400, 604_800, 172_800);
+        assert_eq!(cfg.version, CONFIG_VERSION);
+        assert_eq!(cfg.admin, v0.owner);
+        assert_eq!(cfg.active_relayers(), &[v0.owner]);
+        assert_eq!(cfg.slash_collector, v0.owner);
+        assert_eq!(cfg.claim_window_secs, 1_200);
+        assert_eq!(cfg.min_solver_bond, 20_000_000);
+        assert_eq!(cfg.protocol_fee_bps, 0);
+        assert_eq!(cfg.refund_timeout_secs, 86_400);
+        assert_eq!(cfg.bump, 254);
+
+        // Round-trips through the current account layout
+        let mut out = Vec::new();
+        cfg.try_serialize(&mut out).unwrap();
+        assert!(out.len() <= BridgeConfig::LEN);
+        let back = BridgeConfig::try_deserialize(&mut &out[..]).unwrap();
+        assert_eq!(back.admin, cfg.admin);
+        assert_eq!(out[8], CONFIG_VERSION);
+    }
+
+    #[test]
+    fn request_v0_fixture_upgrades() {
+        let data = request_v0_fixture(true, false);
+        let v0 = BridgeRequestV0::deserialize(&mut &data[8..]).unwrap();
+        assert_eq!(v0.request_id, 42);
+        assert!(v0.claimed && !v0.finalized);
+        assert_eq!(v0.nonce_le, 0x0102_0304);
+        assert_eq!(v0.dest_ct_w3, [8u8; 32]);
+
+        let req = v0.upgrade(1_200, 5_000, 86_400, 1_800_000_000);
+        assert_eq!(req.status, RequestStatus::Claimed);
+        assert_eq!(req.bond_bump, 0);
+        assert!(req.legacy_escrow);
+        assert_eq!(req.bond_lamports, 20_000_000);
+        assert_eq!(req.finalized_at, 0);
+        assert_eq!(req.slash_bps, 5_000);
+        assert_eq!(req.refund_timeout_secs, 86_400);
+
+        let mut out = Vec::new();
+        req.try_serialize(&mut out).unwrap();
+        assert!(out.len() <= BridgeRequest::LEN);
+        assert_eq!(
+            out[BridgeRequest::STATUS_OFFSET],
+            RequestStatus::Claimed as u8
+        );
+        assert_eq!(out[BridgeRequest::VERSION_OFFSET], REQUEST_VERSION);
+    }
+
+    #[test]
+    fn request_v0_status_mapping() {
+        let status = |claimed, finalized| {
+            let data = request_v0_fixture(claimed, finalized);
+            BridgeRequestV0::deserialize(&mut &data[8..])
+                .unwrap()
+                .upgrade(1_200, 5_000, 86_400, 7)
+        };
+        assert_eq!(
+            status(false, false).status,
+            RequestStatus::AttestationFailed
+        );
+        assert_eq!(status(true, false).status, RequestStatus::Claimed);
+        let settled = status(true, true);
+        assert_eq!(settled.status, RequestStatus::Settled);
+        assert_eq!(settled.finalized_at, 7);
+        // nothing left to move out of the shared escrow once settled
+        assert!(!settled.legacy_escrow);
+    }
+}
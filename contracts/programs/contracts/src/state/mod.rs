@@ -1,5 +1,49 @@
 pub mod request;
-pub use request::BridgeRequest;
+pub use request::{BridgeRequest, RequestStatus, REQUEST_VERSION};
 
 pub mod config;
-pub use config::BridgeConfig;
+pub use config::{
+    BridgeConfig, CONFIG_VERSION, DEFAULT_MAX_COMPUTE_FAILURES, MAX_RELAYERS, PAUSE_ALL,
+    PAUSE_CLAIMS, PAUSE_DELIVERIES, PAUSE_DEPOSITS, PAUSE_SETTLEMENTS,
+};
+
+pub mod token_config;
+pub use token_config::TokenConfig;
+
+pub mod token_route;
+pub use token_route::TokenRoute;
+
+pub mod chain_config;
+pub use chain_config::ChainConfig;
+
+pub mod fee_vault;
+pub use fee_vault::FeeVault;
+
+pub mod solver;
+pub use solver::SolverEntry;
+
+pub mod pending_config;
+pub use pending_config::PendingConfigChange;
+
+pub mod legacy;
+pub use legacy::{BridgeConfigV0, BridgeRequestV0};
+
+pub mod circuit;
+pub use circuit::{
+    circuit_name, version_comp_def_offset, CircuitRegistry, CircuitVersion, CIRCUIT_PLAN_PAYOUT,
+    CIRCUIT_RESEAL,
+};
+
+pub mod claim_reseal;
+pub use claim_reseal::{ClaimReseal, ResealStatus};
+
+pub mod delivery_receipt;
+pub use delivery_receipt::DeliveryReceipt;
+
+pub mod action_votes;
+pub use action_votes::{
+    require_quorum, ActionVotes, VOTE_KIND_DELIVERY, VOTE_KIND_SETTLEMENT, VOTE_TTL_SECS,
+};
+
+pub mod attester_set;
+pub use attester_set::{AttesterSet, SignatureScheme, MAX_ATTESTERS};
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{BridgeConfig, ChainConfig};
+
+/// Timelocked change to the solver-facing config terms. PDA: [b"pending_config"]
+/// At most one change is pending; `None` fields are left untouched on execute. The `chain_*`
+/// fields change the `ChainConfig` of `chain_id` (claims read its bond live).
+#[account]
+pub struct PendingConfigChange {
+    pub proposer: Pubkey,
+    pub claim_window_secs: Option<i64>,
+    pub min_solver_bond: Option<u64>,
+    pub slash_bps: Option<u16>,
+    pub protocol_fee_bps: Option<u16>,
+    pub config_timelock_secs: Option<i64>,
+    pub chain_id: u64, // meaningful only when a chain_* field is set
+    pub chain_claim_window_secs: Option<i64>,
+    pub chain_min_solver_bond: Option<u64>,
+    pub proposed_at: i64,
+    pub eta: i64, // earliest execution time
+    pub bump: u8,
+}
+
+impl PendingConfigChange {
+    pub fn apply_to(&self, cfg: &mut BridgeConfig) {
+        if let Some(v) = self.claim_window_secs {
+            cfg.claim_window_secs = v;
+        }
+        if let Some(v) = self.min_solver_bond {
+            cfg.min_solver_bond = v;
+        }
+        if let Some(v) = self.slash_bps {
+            cfg.slash_bps = v;
+        }
+        if let Some(v) = self.protocol_fee_bps {
+            cfg.protocol_fee_bps = v;
+        }
+        if let Some(v) = self.config_timelock_secs {
+            cfg.config_timelock_secs = v;
+        }
+    }
+
+    pub fn changes_chain(&self) -> bool {
+        self.chain_claim_window_secs.is_some() || self.chain_min_solver_bond.is_some()
+    }
+
+    pub fn apply_to_chain(&self, chain: &mut ChainConfig) {
+        if let Some(v) = self.chain_claim_window_secs {
+            chain.claim_window_secs = v;
+        }
+        if let Some(v) = self.chain_min_solver_bond {
+            chain.min_solver_bond = v;
+        }
+    }
+}
@@ -1,20 +1,94 @@
 use anchor_lang::prelude::*;
+use core::mem::size_of;
+
+use crate::errors::ErrorCode;
+
+/// Current `BridgeRequest` layout. 0 = pre-versioning layout (see `state::legacy`).
+pub const REQUEST_VERSION: u8 = 1;
+
+/// Lifecycle of a `BridgeRequest`. Stored as a single byte (variant index, so only append
+/// new variants); every change after creation goes through `BridgeRequest::transition`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RequestStatus {
+    /// Funds locked, waiting for attestation / a solver
+    Pending,
+    /// The MPC accepted the destination ciphertext
+    Attested,
+    /// A solver holds an active claim (bond locked)
+    Claimed,
+    /// Paid out to the solver (terminal)
+    Settled,
+    /// Returned to the payer (terminal)
+    Refunded,
+    /// The `plan_payout` computation aborted; can be requeued, refunded or cancelled
+    AttestationFailed,
+    /// Returned to the payer by the admin (terminal)
+    Cancelled,
+    /// Held during an incident; can only be refunded (unclaimed) or cancelled
+    Frozen,
+}
+
+impl RequestStatus {
+    /// Every variant, in discriminant order.
+    pub const ALL: [RequestStatus; 8] = [
+        RequestStatus::Pending,
+        RequestStatus::Attested,
+        RequestStatus::Claimed,
+        RequestStatus::Settled,
+        RequestStatus::Refunded,
+        RequestStatus::AttestationFailed,
+        RequestStatus::Cancelled,
+        RequestStatus::Frozen,
+    ];
+
+    /// Single source of truth for the legal moves of the state machine.
+    pub fn can_transition_to(self, next: RequestStatus) -> bool {
+        use RequestStatus::*;
+        matches!(
+            (self, next),
+            (
+                Pending,
+                Attested | AttestationFailed | Refunded | Cancelled | Frozen
+            ) | (Attested, Claimed | Refunded | Cancelled | Frozen)
+                | (Claimed, Attested | Settled | Frozen)
+                | (AttestationFailed, Pending | Refunded | Cancelled | Frozen)
+                | (Frozen, Refunded | Cancelled)
+        )
+    }
+}
 
 #[account]
 pub struct BridgeRequest {
-    // --- basic / state ---
+    // --- lifecycle / layout (fixed offsets, see STATUS_OFFSET / VERSION_OFFSET) ---
+    pub status: RequestStatus,
+    pub version: u8, // layout version, see REQUEST_VERSION
+
+    // --- basic ---
     pub request_id: u64,
     pub payer: Pubkey,
     pub token_mint: Pubkey,
     pub amount_locked: u64,
     pub fee_locked: u64,
+    pub token_config_version: u32, // TokenConfig version the fee was priced against
     pub created_at: i64,
-    pub claimed: bool,
     pub solver: Pubkey,
     pub claim_deadline: i64,
     pub bond_lamports: u64,
-    pub finalized: bool,
     pub bump: u8,
+    pub bond_bump: u8, // bond vault bump; 0 = claim still in a legacy request_id-keyed vault
+    pub finalized_at: i64, // set when Settled/Refunded/Cancelled (close grace period)
+
+    // --- destination (resolved from the TokenRoute at deposit) ---
+    pub dest_chain_id: u64,
+    pub dest_token: [u8; 20],       // EVM token address
+    pub expected_dest_amount: u128, // amount_locked in destination token units
+    pub dest_gas_fee_lamports: u64, // held by this PDA on top of rent; to the solver or back to the payer
+
+    // --- terms snapshotted at deposit (config changes don't affect open requests) ---
+    pub claim_window_secs: i64,
+    pub refund_timeout_secs: i64,
+    pub slash_bps: u16,
+    pub protocol_fee_bps: u16,
 
     // --- material for reseal (new) ---
     pub client_pubkey: [u8; 32], // client's ephemeral x25519
@@ -23,4 +97,101 @@ pub struct BridgeRequest {
     pub dest_ct_w1: [u8; 32],
     pub dest_ct_w2: [u8; 32],
     pub dest_ct_w3: [u8; 32],
+
+    // --- MPC results ---
+    pub attested_nonce: u128, // output nonce of plan_payout, set with Attested
+    pub compute_failures: u8, // aborted plan_payout / reseal computations
+
+    /// Funds still in the shared sign-PDA escrow (migrated V0 request); see migrate_request_escrow
+    pub legacy_escrow: bool,
+
+    // --- claim accounts (rent goes back to whoever paid it on close) ---
+    pub bond_rent_payer: Pubkey, // created the per-request bond vault; default = unknown (payer)
+    pub ever_claimed: bool,      // a claim created the ClaimReseal; close_request must close it
+
+    /// Room for new fields without a realloc; must stay zeroed until used
+    pub reserved: [u8; 5],
+}
+
+impl BridgeRequest {
+    /// Byte offset of `status` in the account data (right after the discriminator).
+    /// Indexers can filter requests by state with a 1-byte `memcmp` at this offset.
+    pub const STATUS_OFFSET: usize = 8;
+    /// Byte offset of `version`.
+    pub const VERSION_OFFSET: usize = 9;
+    /// Account size (discriminator included) of the current layout.
+    pub const LEN: usize = 8 + size_of::<BridgeRequest>();
+
+    /// Whether a destination ciphertext is stored; every path that stores one queues
+    /// `plan_payout` on it, so a `Pending` request with one has a computation in flight.
+    pub fn has_destination(&self) -> bool {
+        self.client_pubkey != [0u8; 32]
+    }
+
+    /// Account that gets the bond vault's rent back on close: whoever created the vault,
+    /// or the payer for vaults created before it was recorded.
+    pub fn bond_rent_recipient(&self) -> Pubkey {
+        if self.bond_rent_payer == Pubkey::default() {
+            self.payer
+        } else {
+            self.bond_rent_payer
+        }
+    }
+
+    /// Checked state change; the only place `status` is written after creation.
+    pub fn transition(&mut self, next: RequestStatus) -> Result<()> {
+        require!(
+            self.status.can_transition_to(next),
+            ErrorCode::InvalidStatusTransition
+        );
+        self.status = next;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RequestStatus::{self, *};
+
+    /// Every legal (from, to) move; any pair not listed here must be rejected.
+    const LEGAL: &[(RequestStatus, RequestStatus)] = &[
+        (Pending, Attested),
+        (Pending, AttestationFailed),
+        (Pending, Refunded),
+        (Pending, Cancelled),
+        (Pending, Frozen),
+        (Attested, Claimed),
+        (Attested, Refunded),
+        (Attested, Cancelled),
+        (Attested, Frozen),
+        (Claimed, Attested), // claim released (expired or failed reseal)
+        (Claimed, Settled),
+        (Claimed, Frozen), // the bond stays locked until the admin cancels
+        (AttestationFailed, Pending),
+        (AttestationFailed, Refunded),
+        (AttestationFailed, Cancelled),
+        (AttestationFailed, Frozen),
+        (Frozen, Refunded),
+        (Frozen, Cancelled),
+    ];
+
+    #[test]
+    fn transition_table_is_exhaustive() {
+        for from in RequestStatus::ALL {
+            for to in RequestStatus::ALL {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    LEGAL.contains(&(from, to)),
+                    "{from:?} -> {to:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn all_lists_variants_in_discriminant_order() {
+        for (i, status) in RequestStatus::ALL.iter().enumerate() {
+            assert_eq!(*status as usize, i);
+        }
+    }
 }
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// Solver registry entry (EVM `authorizeNode`). PDA: [b"solver", solver]
+/// The account exists only while the solver is authorized; list them with getProgramAccounts.
+#[account]
+pub struct SolverEntry {
+    pub solver: Pubkey,
+    pub x25519: [u8; 32], // key the destination is resealed to
+    pub authorized_at: i64,
+    pub bump: u8,
+}
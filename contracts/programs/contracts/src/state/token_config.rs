@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+/// Per-mint bridging rules. PDA: [b"token_config", mint]
+#[account]
+pub struct TokenConfig {
+    pub mint: Pubkey,
+    pub enabled: bool,
+    pub decimals: u8,     // copied from the mint
+    pub min_deposit: u64, // in mint units (gross)
+    pub max_deposit: u64, // in mint units (gross)
+    pub fee_bps: u16,     // 0..=1000 (10%)
+    pub min_fee: u64,     // in mint units
+    pub max_fee: u64,     // in mint units
+    pub version: u32,     // bumped when the fee schedule changes; recorded on each request
+    pub bump: u8,
+}
+
+impl TokenConfig {
+    /// The fields a request is priced with; `version` tracks changes to these only.
+    pub fn fee_schedule(&self) -> (u16, u64, u64) {
+        (self.fee_bps, self.min_fee, self.max_fee)
+    }
+
+    /// Checks the deposit limits and returns `(fee, amount_net)` for a gross `amount`.
+    pub fn quote(&self, amount: u64) -> Result<(u64, u64)> {
+        require!(self.enabled, ErrorCode::TokenDisabled);
+        require!(amount >= self.min_deposit, ErrorCode::DepositBelowMinimum);
+        require!(amount <= self.max_deposit, ErrorCode::DepositAboveMaximum);
+
+        let mut fee = (amount as u128 * self.fee_bps as u128) / 10_000;
+        if fee < self.min_fee as u128 {
+            fee = self.min_fee as u128;
+        }
+        if fee > self.max_fee as u128 {
+            fee = self.max_fee as u128;
+        }
+        if fee as u64 >= amount {
+            fee = (amount / 2) as u128;
+        }
+        let fee_u64 = u64::try_from(fee).unwrap_or(u64::MAX);
+        Ok((fee_u64, amount.saturating_sub(fee_u64)))
+    }
+}
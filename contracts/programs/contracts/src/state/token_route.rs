@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+/// Maps a Solana mint to its token on a destination chain.
+/// PDA: [b"token_route", mint, dest_chain_id (LE)]
+#[account]
+pub struct TokenRoute {
+    pub mint: Pubkey,
+    pub dest_chain_id: u64,
+    pub dest_token: [u8; 20], // EVM token address
+    pub dest_decimals: u8,
+    pub enabled: bool,
+    pub bump: u8,
+}
+
+impl TokenRoute {
+    /// Converts `amount` (in units of a mint with `src_decimals`) to destination units.
+    /// Scaling down truncates, so the solver is never asked for more than was locked: the
+    /// dropped digits are not part of the request's `expected_dest_amount`.
+    pub fn to_dest_amount(&self, amount: u64, src_decimals: u8) -> Result<u128> {
+        let amount = amount as u128;
+        if self.dest_decimals >= src_decimals {
+            let scale = 10u128
+                .checked_pow((self.dest_decimals - src_decimals) as u32)
+                .ok_or(ErrorCode::MathOverflow)?;
+            Ok(amount.checked_mul(scale).ok_or(ErrorCode::MathOverflow)?)
+        } else {
+            let scale = 10u128
+                .checked_pow((src_decimals - self.dest_decimals) as u32)
+                .ok_or(ErrorCode::MathOverflow)?;
+            Ok(amount / scale)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(dest_decimals: u8) -> TokenRoute {
+        TokenRoute {
+            mint: Pubkey::default(),
+            dest_chain_id: 1,
+            dest_token: [0u8; 20],
+            dest_decimals,
+            enabled: true,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn scales_up_exactly() {
+        // 6-decimal USDC on Solana -> 18-decimal token
+        assert_eq!(
+            route(18).to_dest_amount(1_500_000, 6).unwrap(),
+            1_500_000_000_000_000_000
+        );
+        assert_eq!(route(6).to_dest_amount(1_234, 6).unwrap(), 1_234);
+    }
+
+    #[test]
+    fn scaling_down_truncates() {
+        // 9-decimal SOL -> 6 decimals: the last 3 digits are dropped, never rounded up
+        assert_eq!(route(6).to_dest_amount(1_000_999, 9).unwrap(), 1_000);
+        assert_eq!(route(6).to_dest_amount(999, 9).unwrap(), 0);
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        assert!(route(u8::MAX).to_dest_amount(u64::MAX, 0).is_err());
+    }
+}
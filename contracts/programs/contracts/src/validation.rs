@@ -0,0 +1,299 @@
+//! Bounds for every admin-set parameter, shared by the config, token and chain registries.
+//!
+//! | parameter                                   | bound                                  |
+//! |---------------------------------------------|----------------------------------------|
+//! | `fee_bps`                                   | `0..=MAX_FEE_BPS` (10%)                |
+//! | `min_fee` / `max_fee`                       | `min_fee <= max_fee`                   |
+//! | `claim_window_secs`                         | `MIN_CLAIM_WINDOW_SECS..=MAX_CLAIM_WINDOW_SECS` (chains: also 0 = global) |
+//! | `slash_bps`, `protocol_fee_bps`             | `0..=BPS_DENOMINATOR`                  |
+//! | `min_solver_bond` (global)                  | `> 0`                                  |
+//! | `refund_timeout_secs`                       | `0..=MAX_REFUND_TIMEOUT_SECS` (30 days) |
+//! | `close_grace_secs`                          | `0..=MAX_CLOSE_GRACE_SECS` (90 days)   |
+//! | `config_timelock_secs`                      | `0..=MAX_CONFIG_TIMELOCK_SECS` (30 days) |
+//! | `min_deposit` / `max_deposit`               | `min_deposit <= max_deposit`           |
+//! | `relayer_threshold`                         | `1..=relayer_count` (0 reads as 1)     |
+//! | route `dest_decimals`                       | within `MAX_DECIMALS_DIFF` of the mint |
+
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{BridgeConfig, ChainConfig, TokenConfig, TokenRoute};
+
+pub const BPS_DENOMINATOR: u16 = 10_000;
+pub const MAX_FEE_BPS: u16 = 1_000;
+pub const MIN_CLAIM_WINDOW_SECS: i64 = 60;
+pub const MAX_CLAIM_WINDOW_SECS: i64 = 7 * 24 * 60 * 60;
+/// Upper bounds keep `created_at`/`finalized_at`/`now` + duration far from overflowing, so a
+/// bad value can never lock users out of refunds or requests out of closing.
+pub const MAX_REFUND_TIMEOUT_SECS: i64 = 30 * 24 * 60 * 60;
+pub const MAX_CLOSE_GRACE_SECS: i64 = 90 * 24 * 60 * 60;
+pub const MAX_CONFIG_TIMELOCK_SECS: i64 = 30 * 24 * 60 * 60;
+/// Largest decimals gap of a route: u64::MAX * 10^19 still fits the u128 destination amount.
+pub const MAX_DECIMALS_DIFF: u8 = 19;
+
+pub fn check_fee_schedule(fee_bps: u16, min_fee: u64, max_fee: u64) -> Result<()> {
+    require!(fee_bps <= MAX_FEE_BPS, ErrorCode::FeeBpsTooHigh);
+    require!(min_fee <= max_fee, ErrorCode::MinFeeAboveMaxFee);
+    Ok(())
+}
+
+pub fn check_claim_window(secs: i64) -> Result<()> {
+    require!(
+        (MIN_CLAIM_WINDOW_SECS..=MAX_CLAIM_WINDOW_SECS).contains(&secs),
+        ErrorCode::ClaimWindowOutOfRange
+    );
+    Ok(())
+}
+
+pub fn check_bps(bps: u16) -> Result<()> {
+    require!(bps <= BPS_DENOMINATOR, ErrorCode::BpsOutOfRange);
+    Ok(())
+}
+
+pub fn check_duration(secs: i64, max: i64) -> Result<()> {
+    require!(secs >= 0, ErrorCode::NegativeDuration);
+    require!(secs <= max, ErrorCode::DurationTooLong);
+    Ok(())
+}
+
+pub fn check_relayer_threshold(threshold: u8, relayer_count: u8) -> Result<()> {
+    require!(
+        threshold >= 1 && threshold <= relayer_count,
+        ErrorCode::InvalidRelayerThreshold
+    );
+    Ok(())
+}
+
+pub fn validate_config(cfg: &BridgeConfig) -> Result<()> {
+    check_fee_schedule(cfg.fee_bps, cfg.min_fee, cfg.max_fee)?;
+    check_claim_window(cfg.claim_window_secs)?;
+    require!(cfg.min_solver_bond > 0, ErrorCode::ZeroBondNotAllowed);
+    check_bps(cfg.slash_bps)?;
+    check_bps(cfg.protocol_fee_bps)?;
+    check_duration(cfg.refund_timeout_secs, MAX_REFUND_TIMEOUT_SECS)?;
+    check_duration(cfg.close_grace_secs, MAX_CLOSE_GRACE_SECS)?;
+    check_duration(cfg.config_timelock_secs, MAX_CONFIG_TIMELOCK_SECS)?;
+    check_relayer_threshold(cfg.quorum(), cfg.relayer_count)?;
+    Ok(())
+}
+
+pub fn validate_token_config(tc: &TokenConfig) -> Result<()> {
+    check_fee_schedule(tc.fee_bps, tc.min_fee, tc.max_fee)?;
+    require!(
+        tc.min_deposit <= tc.max_deposit,
+        ErrorCode::MinDepositAboveMaxDeposit
+    );
+    Ok(())
+}
+
+/// `src_decimals`: decimals of the route's mint (its TokenConfig).
+pub fn validate_token_route(route: &TokenRoute, src_decimals: u8) -> Result<()> {
+    require!(
+        route.dest_decimals.abs_diff(src_decimals) <= MAX_DECIMALS_DIFF,
+        ErrorCode::DestDecimalsOutOfRange
+    );
+    Ok(())
+}
+
+pub fn validate_chain_config(chain: &ChainConfig) -> Result<()> {
+    // 0 falls back to the global window
+    if chain.claim_window_secs != 0 {
+        check_claim_window(chain.claim_window_secs)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn err<T>(r: Result<T>, code: ErrorCode) -> bool {
+        r.err() == Some(code.into())
+    }
+
+    #[test]
+    fn fee_schedule_bounds() {
+        assert!(check_fee_schedule(0, 0, 0).is_ok());
+        assert!(check_fee_schedule(MAX_FEE_BPS, 5, 5).is_ok());
+        assert!(err(
+            check_fee_schedule(MAX_FEE_BPS + 1, 0, 0),
+            ErrorCode::FeeBpsTooHigh
+        ));
+        assert!(err(
+            check_fee_schedule(30, 6, 5),
+            ErrorCode::MinFeeAboveMaxFee
+        ));
+    }
+
+    #[test]
+    fn claim_window_bounds() {
+        assert!(check_claim_window(MIN_CLAIM_WINDOW_SECS).is_ok());
+        assert!(check_claim_window(MAX_CLAIM_WINDOW_SECS).is_ok());
+        for secs in [-1, 0, MIN_CLAIM_WINDOW_SECS - 1, MAX_CLAIM_WINDOW_SECS + 1] {
+            assert!(err(
+                check_claim_window(secs),
+                ErrorCode::ClaimWindowOutOfRange
+            ));
+        }
+    }
+
+    #[test]
+    fn bps_bounds() {
+        assert!(check_bps(0).is_ok());
+        assert!(check_bps(BPS_DENOMINATOR).is_ok());
+        assert!(err(
+            check_bps(BPS_DENOMINATOR + 1),
+            ErrorCode::BpsOutOfRange
+        ));
+    }
+
+    #[test]
+    fn duration_bounds() {
+        for max in [
+            MAX_REFUND_TIMEOUT_SECS,
+            MAX_CLOSE_GRACE_SECS,
+            MAX_CONFIG_TIMELOCK_SECS,
+        ] {
+            assert!(check_duration(0, max).is_ok());
+            assert!(check_duration(max, max).is_ok());
+            assert!(err(check_duration(-1, max), ErrorCode::NegativeDuration));
+            assert!(err(
+                check_duration(max + 1, max),
+                ErrorCode::DurationTooLong
+            ));
+            assert!(err(
+                check_duration(i64::MAX, max),
+                ErrorCode::DurationTooLong
+            ));
+        }
+    }
+
+    #[test]
+    fn relayer_threshold_bounds() {
+        assert!(check_relayer_threshold(1, 1).is_ok());
+        assert!(check_relayer_threshold(3, 3).is_ok());
+        assert!(err(
+            check_relayer_threshold(0, 3),
+            ErrorCode::InvalidRelayerThreshold
+        ));
+        assert!(err(
+            check_relayer_threshold(4, 3),
+            ErrorCode::InvalidRelayerThreshold
+        ));
+    }
+
+    #[test]
+    fn chain_window_zero_means_global() {
+        let mut chain = ChainConfig {
+            chain_id: 1,
+            enabled: true,
+            claim_window_secs: 0,
+            min_solver_bond: 0,
+            dest_gas_fee_lamports: 0,
+            bump: 0,
+        };
+        assert!(validate_chain_config(&chain).is_ok());
+        chain.claim_window_secs = MIN_CLAIM_WINDOW_SECS - 1;
+        assert!(err(
+            validate_chain_config(&chain),
+            ErrorCode::ClaimWindowOutOfRange
+        ));
+    }
+
+    #[test]
+    fn token_deposit_limits() {
+        let mut tc = TokenConfig {
+            mint: Pubkey::default(),
+            enabled: true,
+            decimals: 6,
+            min_deposit: 10,
+            max_deposit: 10,
+            fee_bps: 30,
+            min_fee: 1,
+            max_fee: 2,
+            version: 1,
+            bump: 0,
+        };
+        assert!(validate_token_config(&tc).is_ok());
+        tc.min_deposit = 11;
+        assert!(err(
+            validate_token_config(&tc),
+            ErrorCode::MinDepositAboveMaxDeposit
+        ));
+    }
+
+    #[test]
+    fn route_decimals_gap_bounded() {
+        let mut route = TokenRoute {
+            mint: Pubkey::default(),
+            dest_chain_id: 1,
+            dest_token: [0u8; 20],
+            dest_decimals: 6 + MAX_DECIMALS_DIFF,
+            enabled: true,
+            bump: 0,
+        };
+        assert!(validate_token_route(&route, 6).is_ok());
+        // the widest accepted gap never overflows the conversion
+        assert!(route.to_dest_amount(u64::MAX, 6).is_ok());
+        route.dest_decimals += 1;
+        assert!(err(
+            validate_token_route(&route, 6),
+            ErrorCode::DestDecimalsOutOfRange
+        ));
+        route.dest_decimals = 0;
+        assert!(validate_token_route(&route, MAX_DECIMALS_DIFF).is_ok());
+        assert!(err(
+            validate_token_route(&route, MAX_DECIMALS_DIFF + 1),
+            ErrorCode::DestDecimalsOutOfRange
+        ));
+    }
+
+    #[test]
+    fn global_config_bounds() {
+        let mut cfg = BridgeConfig {
+            version: crate::state::CONFIG_VERSION,
+            admin: Pubkey::default(),
+            fee_bps: 30,
+            min_fee: 1,
+            max_fee: 2,
+            claim_window_secs: 1200,
+            min_solver_bond: 1,
+            slash_bps: 5000,
+            refund_timeout_secs: 0,
+            close_grace_secs: 0,
+            protocol_fee_bps: 0,
+            config_timelock_secs: 0,
+            pending_admin: Pubkey::default(),
+            relayers: [Pubkey::default(); crate::state::MAX_RELAYERS],
+            relayer_count: 1,
+            fee_collector: Pubkey::default(),
+            slash_collector: Pubkey::default(),
+            pauser: Pubkey::default(),
+            paused: 0,
+            bump: 0,
+            max_compute_failures: 0,
+            relayer_threshold: 1,
+            reserved: [0u8; 62],
+        };
+        assert!(validate_config(&cfg).is_ok());
+        cfg.relayer_threshold = 2;
+        assert!(err(
+            validate_config(&cfg),
+            ErrorCode::InvalidRelayerThreshold
+        ));
+        cfg.relayer_threshold = 0; // pre-threshold configs: read as 1
+        assert!(validate_config(&cfg).is_ok());
+        cfg.refund_timeout_secs = MAX_REFUND_TIMEOUT_SECS + 1;
+        assert!(err(validate_config(&cfg), ErrorCode::DurationTooLong));
+        cfg.refund_timeout_secs = MAX_REFUND_TIMEOUT_SECS;
+        cfg.close_grace_secs = MAX_CLOSE_GRACE_SECS + 1;
+        assert!(err(validate_config(&cfg), ErrorCode::DurationTooLong));
+        cfg.close_grace_secs = MAX_CLOSE_GRACE_SECS;
+        cfg.config_timelock_secs = MAX_CONFIG_TIMELOCK_SECS + 1;
+        assert!(err(validate_config(&cfg), ErrorCode::DurationTooLong));
+        cfg.config_timelock_secs = MAX_CONFIG_TIMELOCK_SECS;
+        assert!(validate_config(&cfg).is_ok());
+        cfg.min_solver_bond = 0;
+        assert!(err(validate_config(&cfg), ErrorCode::ZeroBondNotAllowed));
+    }
+}
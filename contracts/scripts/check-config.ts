@@ -45,9 +45,17 @@ function toCamel(name: string) {
       claimWindowSecs: cfg.claimWindowSecs?.toString?.() ?? String(cfg.claimWindowSecs),
       minSolverBond: cfg.minSolverBond?.toString?.() ?? String(cfg.minSolverBond),
       slashBps: cfg.slashBps,
+      maxComputeFailures: cfg.maxComputeFailures,
+      relayerThreshold: cfg.relayerThreshold,
       bump: cfg.bump,
-      // owner si existiera en tu struct:
-      owner: cfg.owner?.toBase58?.() ?? cfg.owner ?? "(no owner field)",
+      admin: cfg.admin?.toBase58?.(),
+      pendingAdmin: cfg.pendingAdmin?.toBase58?.(),
+      relayers: (cfg.relayers ?? [])
+        .slice(0, cfg.relayerCount ?? 0)
+        .map((k: any) => k.toBase58()),
+      feeCollector: cfg.feeCollector?.toBase58?.(),
+      slashCollector: cfg.slashCollector?.toBase58?.(),
+      pauser: cfg.pauser?.toBase58?.(),
     });
   } catch (e) {
     console.error("❌ No pude deserializar config con este IDL:", e);
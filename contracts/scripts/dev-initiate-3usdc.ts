@@ -24,10 +24,12 @@ import {
   getMempoolAccAddress,
   getExecutingPoolAccAddress,
   getCompDefAccAddress,
-  getCompDefAccOffset,
   getComputationAccAddress,
 } from "@arcium-hq/client";
 
+// Circuit id used in the registry seeds (state/circuit.rs)
+const CIRCUIT_PLAN_PAYOUT = 0;
+
 /* ============ utils ============ */
 
 function requireEnv(name: string): string {
@@ -142,6 +144,8 @@ function clusterPdaFromOffset(
 
   // === SPL / Bridge locals ===
   const MINT = new PublicKey(requireEnv("NEXT_PUBLIC_SPL_MINT"));
+  // Destination chain (Sepolia by default); needs a chain config and a token_route for MINT
+  const DEST_CHAIN_ID = BigInt(process.env.DEST_CHAIN_ID ?? "11155111");
   const SIGN_SEED_STR = process.env.NEXT_PUBLIC_SIGN_SEED || "SignerAccount";
   const SIGN_SEED = Buffer.from(SIGN_SEED_STR);
 
@@ -151,10 +155,21 @@ function clusterPdaFromOffset(
   const MEMPOOL  = getMempoolAccAddress(programId);
   const EXECPOOL = getExecutingPoolAccAddress(programId);
 
-  // comp_def "plan_payout"
-  const planOffsetU32 = Buffer
-    .from(getCompDefAccOffset("plan_payout"))
-    .readUInt32LE();
+  // comp_def "plan_payout": the active version comes from the circuit registry
+  const [CIRCUIT_REGISTRY_PDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("circuit"), Buffer.from([CIRCUIT_PLAN_PAYOUT])],
+    programId
+  );
+  const registry: any = await (program.account as any).circuitRegistry.fetch(
+    CIRCUIT_REGISTRY_PDA
+  );
+  const activeVersion = Buffer.alloc(4);
+  activeVersion.writeUInt32LE(Number(registry.activeVersion));
+  const [CIRCUIT_VERSION_PDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("circuit_version"), Buffer.from([CIRCUIT_PLAN_PAYOUT]), activeVersion],
+    programId
+  );
+  const planOffsetU32 = Number(registry.activeOffset);
   const COMPDEF_PLAN  = getCompDefAccAddress(programId, planOffsetU32);
 
   // Cluster derivado por offset fijo de devnet
@@ -171,6 +186,18 @@ function clusterPdaFromOffset(
     [Buffer.from("config")],
     programId
   );
+  const [TOKEN_CONFIG_PDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("token_config"), MINT.toBuffer()],
+    programId
+  );
+  const [CHAIN_CONFIG_PDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("chain"), u64LE(DEST_CHAIN_ID)],
+    programId
+  );
+  const [TOKEN_ROUTE_PDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("token_route"), MINT.toBuffer(), u64LE(DEST_CHAIN_ID)],
+    programId
+  );
   const requestId = BigInt(Date.now() % 2 ** 32);
   const [REQUEST_PDA] = PublicKey.findProgramAddressSync(
     [Buffer.from("request"), PAYER.toBuffer(), u64LE(requestId)],
@@ -263,12 +290,17 @@ function clusterPdaFromOffset(
     mint: MINT.toBase58(),
     escrowToken: escrowToken.toBase58(),
     config: CONFIG_PDA.toBase58(),
+    tokenConfig: TOKEN_CONFIG_PDA.toBase58(),
+    chainConfig: CHAIN_CONFIG_PDA.toBase58(),
+    tokenRoute: TOKEN_ROUTE_PDA.toBase58(),
     requestPda: REQUEST_PDA.toBase58(),
     signPdaAccount: SIGN_PDA.toBase58(),
     mxeAccount: MXE.toBase58(),
     mempoolAccount: MEMPOOL.toBase58(),
     executingPool: EXECPOOL.toBase58(),
     computationAccount: computationPda.toBase58(),
+    circuitRegistry: CIRCUIT_REGISTRY_PDA.toBase58(),
+    circuitVersion: CIRCUIT_VERSION_PDA.toBase58(),
     compDefAccount: COMPDEF_PLAN.toBase58(),
     clusterAccount: CLUSTER.toBase58(),
     poolAccount: FEE_POOL.toBase58(),
@@ -297,6 +329,7 @@ function clusterPdaFromOffset(
       .initiateBridge(
         new BN(offset.toString()),
         new BN(requestId.toString()),
+        new BN(DEST_CHAIN_ID.toString()),
         Buffer.from(clientPub),
         Buffer.from(nonce),
         Buffer.from(ct0),
@@ -315,6 +348,9 @@ function clusterPdaFromOffset(
 
         // Bridge
         config: CONFIG_PDA,
+        tokenConfig: TOKEN_CONFIG_PDA,
+        chainConfig: CHAIN_CONFIG_PDA,
+        tokenRoute: TOKEN_ROUTE_PDA,
         requestPda: REQUEST_PDA,
         signPdaAccount: SIGN_PDA,
 
@@ -323,6 +359,8 @@ function clusterPdaFromOffset(
         mempoolAccount: MEMPOOL,
         executingPool: EXECPOOL,
         computationAccount: compPk,
+        circuitRegistry: CIRCUIT_REGISTRY_PDA,
+        circuitVersion: CIRCUIT_VERSION_PDA,
         compDefAccount: COMPDEF_PLAN,
         clusterAccount: CLUSTER,
         poolAccount: FEE_POOL,
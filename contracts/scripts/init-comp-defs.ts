@@ -23,6 +23,30 @@ const PROGRAM_ID = new PublicKey("8gk2T4FJYaPUWHDzm5aKccu8HJSpEXYu3rFAoeb7FDE7")
 // Arcium program on devnet
 const ARCIUM_PROGRAM_ID = new PublicKey("BKck65TgoKRokMjQM3datB9oRwJ8rAj2jxPXvHXUvcL6");
 
+// Where the MXE fetches each compiled circuit. Each URL must serve the exact
+// build/<circuit>.arcis whose hash is pinned in programs/contracts/src/circuit_hashes.rs
+// (the program build fails when the two drift).
+const PLAN_PAYOUT_CIRCUIT_URL = process.env.PLAN_PAYOUT_CIRCUIT_URL || "";
+const RESEAL_CIRCUIT_URL = process.env.RESEAL_CIRCUIT_URL || "";
+
+// Circuit ids used in the registry seeds (state/circuit.rs)
+const CIRCUIT_PLAN_PAYOUT = 0;
+const CIRCUIT_RESEAL = 1;
+
+function circuitPdas(circuit: number) {
+  const version = Buffer.alloc(4);
+  version.writeUInt32LE(1);
+  const [registry] = PublicKey.findProgramAddressSync(
+    [Buffer.from("circuit"), Buffer.from([circuit])],
+    PROGRAM_ID
+  );
+  const [circuitVersion] = PublicKey.findProgramAddressSync(
+    [Buffer.from("circuit_version"), Buffer.from([circuit]), version],
+    PROGRAM_ID
+  );
+  return { registry, circuitVersion };
+}
+
 /* ============== Load wallet / provider ============== */
 
 function loadKeypair(): Keypair {
@@ -38,6 +62,11 @@ function loadKeypair(): Keypair {
   return Keypair.fromSecretKey(Uint8Array.from(JSON.parse(raw)));
 }
 
+function requireUrl(name: string, url: string): string {
+  if (!url) throw new Error(`Set ${name} to the URL serving the compiled circuit`);
+  return url;
+}
+
 async function main() {
   const connection = new anchor.web3.Connection(CLUSTER_URL, "confirmed");
   const kp = loadKeypair();
@@ -64,13 +93,21 @@ async function main() {
   console.log("plan_payout comp_def PDA:", planPayoutCompDef.toBase58());
   console.log("reseal_destination comp_def PDA:", resealCompDef.toBase58());
 
+  // The comp-def inits are admin-only and register version 1 of each circuit
+  const [configPda] = PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+  const plan = circuitPdas(CIRCUIT_PLAN_PAYOUT);
+  const reseal = circuitPdas(CIRCUIT_RESEAL);
+
   // === Idempotency: if the comp_def already exists, skip ===
   const planExists = await connection.getAccountInfo(planPayoutCompDef);
   if (!planExists) {
     const sig = await program.methods
-      .initPlanPayoutCompDef()
+      .initPlanPayoutCompDef(requireUrl("PLAN_PAYOUT_CIRCUIT_URL", PLAN_PAYOUT_CIRCUIT_URL))
       .accounts({
         payer: wallet.publicKey,
+        config: configPda,
+        circuitRegistry: plan.registry,
+        circuitVersion: plan.circuitVersion,
         mxeAccount,
         compDefAccount: planPayoutCompDef,
         arciumProgram: ARCIUM_PROGRAM_ID,
@@ -84,9 +121,12 @@ async function main() {
   const resealExists = await connection.getAccountInfo(resealCompDef);
   if (!resealExists) {
     const sig = await program.methods
-      .initResealCompDef()
+      .initResealCompDef(requireUrl("RESEAL_CIRCUIT_URL", RESEAL_CIRCUIT_URL))
       .accounts({
         payer: wallet.publicKey,
+        config: configPda,
+        circuitRegistry: reseal.registry,
+        circuitVersion: reseal.circuitVersion,
         mxeAccount,
         compDefAccount: resealCompDef,
         arciumProgram: ARCIUM_PROGRAM_ID,
@@ -21,6 +21,14 @@ const CLAIM_WINDOW_SECS = BigInt(process.env.CLAIM_WINDOW_SECS ?? "1200"); // 20
 // Minimum solver bond: 0.02 SOL in lamports
 const MIN_SOLVER_BOND = BigInt(process.env.MIN_SOLVER_BOND ?? "20000000"); // 0.02 SOL
 const SLASH_BPS = Number(process.env.SLASH_BPS ?? 5000);            // 50%
+// Unclaimed requests become refundable to the payer after this
+const REFUND_TIMEOUT_SECS = BigInt(process.env.REFUND_TIMEOUT_SECS ?? "86400"); // 24h
+// Settled/refunded requests can be closed (rent back to payer) after this
+const CLOSE_GRACE_SECS = BigInt(process.env.CLOSE_GRACE_SECS ?? "604800"); // 7 days
+// Share of each request fee kept by the protocol (rest goes to the solver)
+const PROTOCOL_FEE_BPS = Number(process.env.PROTOCOL_FEE_BPS ?? 0);
+// Notice given to solvers before claim window / bond / slash / protocol fee changes apply
+const CONFIG_TIMELOCK_SECS = BigInt(process.env.CONFIG_TIMELOCK_SECS ?? "172800"); // 48h
 
 // ======================= HELPERS =======================
 
@@ -70,7 +78,11 @@ async function main() {
         new anchor.BN(MAX_FEE.toString()),
         new anchor.BN(CLAIM_WINDOW_SECS.toString()),
         new anchor.BN(MIN_SOLVER_BOND.toString()),
-        SLASH_BPS
+        SLASH_BPS,
+        new anchor.BN(REFUND_TIMEOUT_SECS.toString()),
+        new anchor.BN(CLOSE_GRACE_SECS.toString()),
+        PROTOCOL_FEE_BPS,
+        new anchor.BN(CONFIG_TIMELOCK_SECS.toString())
       )
       .accounts({
         payer: wallet.publicKey,
@@ -95,7 +107,11 @@ async function main() {
       claimWindowSecs: cfg.claimWindowSecs.toString(),
       minSolverBond: cfg.minSolverBond.toString(),
       slashBps: cfg.slashBps,
-      owner: cfg.owner?.toBase58?.() ?? cfg.owner,
+      refundTimeoutSecs: cfg.refundTimeoutSecs?.toString(),
+      closeGraceSecs: cfg.closeGraceSecs?.toString(),
+      protocolFeeBps: cfg.protocolFeeBps,
+      configTimelockSecs: cfg.configTimelockSecs?.toString(),
+      admin: cfg.admin?.toBase58?.() ?? cfg.admin,
       bump: cfg.bump,
     });
   } catch {
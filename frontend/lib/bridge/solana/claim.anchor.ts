@@ -37,8 +37,24 @@ const deriveConfigPda  = (bridge: PublicKey) => pda([Buffer.from("config")], bri
 const deriveRequestPda = (bridge: PublicKey, owner: PublicKey, reqId: bigint) =>
   pda([Buffer.from("request"), owner.toBuffer(), u64LE(reqId)], bridge);
 const deriveSignPda    = (bridge: PublicKey) => pda([Buffer.from(SIGN_SEED)], bridge);
-const deriveBondPda    = (bridge: PublicKey, reqId: bigint) =>
-  pda([Buffer.from("bond"), u64LE(reqId)], bridge);
+const deriveBondPda    = (bridge: PublicKey, request: PublicKey) =>
+  pda([Buffer.from("bond"), request.toBuffer()], bridge);
+const deriveSolverPda  = (bridge: PublicKey, solver: PublicKey) =>
+  pda([Buffer.from("solver"), solver.toBuffer()], bridge);
+const deriveChainPda   = (bridge: PublicKey, chainId: bigint) =>
+  pda([Buffer.from("chain"), u64LE(chainId)], bridge);
+const deriveResealPda  = (bridge: PublicKey, request: PublicKey) =>
+  pda([Buffer.from("reseal"), request.toBuffer()], bridge);
+
+// Circuitos Arcium (state::circuit): registry → versión activa
+const CIRCUIT_RESEAL = 1;
+const deriveCircuitRegistryPda = (bridge: PublicKey, circuit: number) =>
+  pda([Buffer.from("circuit"), Buffer.from([circuit])], bridge);
+const deriveCircuitVersionPda = (bridge: PublicKey, circuit: number, version: number) => {
+  const v = Buffer.alloc(4);
+  v.writeUInt32LE(version);
+  return pda([Buffer.from("circuit_version"), Buffer.from([circuit]), v], bridge);
+};
 const deriveComputation= (arcium: PublicKey, offset: bigint) =>
   pda([Buffer.from("computation"), u64LE(offset)], arcium);
 
@@ -53,7 +69,7 @@ export async function claimBridgeAnchor(params: {
   const provider = getAnchorProvider("confirmed");
   const program  = getBridgeProgram({ provider }); // usa helper robusto
 
-  const payer    = provider.wallet.publicKey;
+  const solver   = provider.wallet.publicKey;
   const ownerPk  = new PublicKey(params.requestOwner);
 
   const bridgePk = BRIDGE_PROGRAM_ID;
@@ -61,9 +77,23 @@ export async function claimBridgeAnchor(params: {
 
   const config   = deriveConfigPda(bridgePk);
   const request  = deriveRequestPda(bridgePk, ownerPk, params.requestId);
-  const bond     = deriveBondPda(bridgePk, params.requestId);
+  const bond     = deriveBondPda(bridgePk, request);
+  const reseal   = deriveResealPda(bridgePk, request);
+  const solverEntry = deriveSolverPda(bridgePk, solver);
   const signPda  = deriveSignPda(bridgePk);
 
+  // chain_config sale del destino guardado en el request
+  const req      = await (program as any).account.bridgeRequest.fetch(request);
+  const chainConfig = deriveChainPda(bridgePk, BigInt(req.destChainId.toString()));
+
+  const circuitRegistry = deriveCircuitRegistryPda(bridgePk, CIRCUIT_RESEAL);
+  const registry = await (program as any).account.circuitRegistry.fetch(circuitRegistry);
+  const circuitVersion = deriveCircuitVersionPda(
+    bridgePk,
+    CIRCUIT_RESEAL,
+    Number(registry.activeVersion)
+  );
+
   const mxe      = ARCIUM_MXE;
   const mempool  = ARCIUM_MEMPOOL;
   const execpool = ARCIUM_EXECPOOL;
@@ -79,24 +109,29 @@ export async function claimBridgeAnchor(params: {
       new BN(params.requestId.toString()),
       Buffer.from(params.solverX25519)
     )
-    .accounts({
-      payer,
+    .accountsStrict({
+      solver,
       // Bridge
       config,
-      request,
+      solverEntry,
+      requestPda: request,
+      chainConfig,
       requestOwner: ownerPk,
       bondVault: bond,
-      signPda,
+      claimReseal: reseal,
+      signPdaAccount: signPda,
 
       // Arcium reseal
-      mxe,
-      mempool,
-      execpool,
-      computation: comp,
-      compDef,
-      cluster,
-      pool,
-      clock,
+      mxeAccount: mxe,
+      mempoolAccount: mempool,
+      executingPool: execpool,
+      computationAccount: comp,
+      circuitRegistry,
+      circuitVersion,
+      compDefAccount: compDef,
+      clusterAccount: cluster,
+      poolAccount: pool,
+      clockAccount: clock,
 
       arciumProgram: arciumPk,
       systemProgram: SystemProgram.programId,
@@ -29,6 +29,7 @@ import {
   BRIDGE_PROGRAM_ID,
   ARCIUM_CLUSTER,
 } from "./env";
+import { CHAIN_ID } from "@/lib/constants";
 
 // Polyfill Buffer (browser)
 if (typeof (globalThis as any).Buffer === "undefined") {
@@ -54,6 +55,22 @@ const deriveConfigPda = (bridge: PublicKey) => pda([Buffer.from("config")], brid
 const deriveRequestPda = (bridge: PublicKey, owner: PublicKey, reqId: bigint) =>
   pda([Buffer.from("request"), owner.toBuffer(), u64LE(reqId)], bridge);
 const deriveSignPda = (bridge: PublicKey) => pda([Buffer.from(SIGN_SEED)], bridge);
+const deriveTokenConfigPda = (bridge: PublicKey, mint: PublicKey) =>
+  pda([Buffer.from("token_config"), mint.toBuffer()], bridge);
+const deriveChainConfigPda = (bridge: PublicKey, chainId: bigint) =>
+  pda([Buffer.from("chain"), u64LE(chainId)], bridge);
+const deriveTokenRoutePda = (bridge: PublicKey, mint: PublicKey, chainId: bigint) =>
+  pda([Buffer.from("token_route"), mint.toBuffer(), u64LE(chainId)], bridge);
+
+// Circuitos Arcium (state::circuit): registry → versión activa
+const CIRCUIT_PLAN_PAYOUT = 0;
+const deriveCircuitRegistryPda = (bridge: PublicKey, circuit: number) =>
+  pda([Buffer.from("circuit"), Buffer.from([circuit])], bridge);
+const deriveCircuitVersionPda = (bridge: PublicKey, circuit: number, version: number) => {
+  const v = Buffer.alloc(4);
+  v.writeUInt32LE(version);
+  return pda([Buffer.from("circuit_version"), Buffer.from([circuit]), v], bridge);
+};
 const deriveComputation = (arcium: PublicKey, offset: bigint) =>
   pda([Buffer.from("computation"), u64LE(offset)], arcium);
 
@@ -120,6 +137,7 @@ export async function initRequestWithEthAnchor(params: {
   requestId: bigint; // u64
   splMint: string; // p.ej. USDC devnet
   ethRecipient: string; // 0x...
+  destChainId?: bigint; // default: NEXT_PUBLIC_CHAIN_ID
   amountLocked?: bigint; // base units (bigint)
   simulateFirst?: boolean; // default true
 }) {
@@ -157,6 +175,17 @@ export async function initRequestWithEthAnchor(params: {
   const config = deriveConfigPda(bridgePk);
   const requestPda = deriveRequestPda(bridgePk, owner, params.requestId);
   const signPdaAccount = deriveSignPda(bridgePk);
+  const destChainId = params.destChainId ?? BigInt(CHAIN_ID);
+  const tokenConfig = deriveTokenConfigPda(bridgePk, mint);
+  const chainConfig = deriveChainConfigPda(bridgePk, destChainId);
+  const tokenRoute = deriveTokenRoutePda(bridgePk, mint, destChainId);
+  const circuitRegistry = deriveCircuitRegistryPda(bridgePk, CIRCUIT_PLAN_PAYOUT);
+  const registry = await (program as any).account.circuitRegistry.fetch(circuitRegistry);
+  const circuitVersion = deriveCircuitVersionPda(
+    bridgePk,
+    CIRCUIT_PLAN_PAYOUT,
+    Number(registry.activeVersion)
+  );
 
   const mxeAccount = ARCIUM_MXE;
   const mempoolAccount = ARCIUM_MEMPOOL;
@@ -196,9 +225,10 @@ export async function initRequestWithEthAnchor(params: {
     TOKEN_PROGRAM_ID,
     ASSOCIATED_TOKEN_PROGRAM_ID
   );
+  // Escrow propio del request (ATA del request PDA); el programa lo crea (init_if_needed)
   const escrowToken = getAssociatedTokenAddressSync(
     mint,
-    signPdaAccount,
+    requestPda,
     true,
     TOKEN_PROGRAM_ID,
     ASSOCIATED_TOKEN_PROGRAM_ID
@@ -227,19 +257,6 @@ export async function initRequestWithEthAnchor(params: {
       )
     );
   }
-  if (!(await conn.getAccountInfo(escrowToken))) {
-    console.debug("[initiateBridge] preIx: crear ATA escrowToken");
-    pre.push(
-      createAssociatedTokenAccountInstruction(
-        payer,
-        escrowToken,
-        signPdaAccount,
-        mint,
-        TOKEN_PROGRAM_ID,
-        ASSOCIATED_TOKEN_PROGRAM_ID
-      )
-    );
-  }
   pre.unshift(ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 }));
 
   // 5) Payload (dummy)
@@ -249,11 +266,13 @@ export async function initRequestWithEthAnchor(params: {
 
   const offsetBN = new BN(offset.toString());
   const reqIdBN = new BN(params.requestId.toString());
+  const destChainIdBN = new BN(destChainId.toString());
   const amountBN = new BN((params.amountLocked ?? 0n).toString());
 
   console.debug("[initiateBridge] amounts", {
     amountLocked_baseUnits: amountBN.toString(),
     requestId: reqIdBN.toString(),
+    destChainId: destChainIdBN.toString(),
     offset: offsetBN.toString(),
   });
 
@@ -266,12 +285,17 @@ export async function initRequestWithEthAnchor(params: {
     escrowToken: escrowToken.toBase58(),
     tokenProgram: TOKEN_PROGRAM_ID.toBase58(),
     config: config.toBase58(),
+    tokenConfig: tokenConfig.toBase58(),
+    chainConfig: chainConfig.toBase58(),
+    tokenRoute: tokenRoute.toBase58(),
     requestPda: requestPda.toBase58(),
     signPdaAccount: signPdaAccount.toBase58(),
     mxeAccount: mxeAccount.toBase58(),
     mempoolAccount: mempoolAccount.toBase58(),
     executingPool: executingPool.toBase58(),
     computationAccount: computationAccount.toBase58(),
+    circuitRegistry: circuitRegistry.toBase58(),
+    circuitVersion: circuitVersion.toBase58(),
     compDefAccount: compDefAccount.toBase58(),
     clusterAccount: clusterAccount.toBase58(),
     poolAccount: poolAccount.toBase58(),
@@ -286,6 +310,9 @@ export async function initRequestWithEthAnchor(params: {
   // Existen y están en el programa correcto
   await mustMint(conn, mint);
   await mustExistAndOwnedBy(conn, "config", config, BRIDGE_PROGRAM_ID);
+  await mustExistAndOwnedBy(conn, "tokenConfig", tokenConfig, BRIDGE_PROGRAM_ID);
+  await mustExistAndOwnedBy(conn, "chainConfig", chainConfig, BRIDGE_PROGRAM_ID);
+  await mustExistAndOwnedBy(conn, "tokenRoute", tokenRoute, BRIDGE_PROGRAM_ID);
   await mustExistAndOwnedBy(conn, "mxeAccount", mxeAccount, ARCIUM_PROGRAM_ID);
   await mustExistAndOwnedBy(conn, "mempoolAccount", mempoolAccount, ARCIUM_PROGRAM_ID);
   await mustExistAndOwnedBy(conn, "executingPool", executingPool, ARCIUM_PROGRAM_ID);
@@ -296,7 +323,7 @@ export async function initRequestWithEthAnchor(params: {
 
   // Leer config via IDL (útil para ver qué espera tu programa en devnet)
   try {
-    const cfg = await (program as any).account.config.fetch(config);
+    const cfg = await (program as any).account.bridgeConfig.fetch(config);
     console.debug("[bridge:init] on-chain Config (IDL decoded):", cfg);
   } catch {
     console.warn(
@@ -337,6 +364,7 @@ export async function initRequestWithEthAnchor(params: {
     .initiateBridge(
       offsetBN,
       reqIdBN,
+      destChainIdBN,
       Buffer.from(clientPub),
       Buffer.from(nonce),
       Buffer.from(ct0),
@@ -350,12 +378,16 @@ export async function initRequestWithEthAnchor(params: {
       payer,
       userToken,
       mint,
-      escrowToken,
       tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
     
       // Bridge
       config,
+      tokenConfig,
+      chainConfig,
+      tokenRoute,
       requestPda,
+      escrowToken,
       signPdaAccount,
     
       // Arcium
@@ -363,6 +395,8 @@ export async function initRequestWithEthAnchor(params: {
       mempoolAccount,
       executingPool,
       computationAccount,
+      circuitRegistry,
+      circuitVersion,
       compDefAccount,
       clusterAccount,
       poolAccount,